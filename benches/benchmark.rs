@@ -1,6 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use fraction::Fraction;
-use rand;
 
 fn sqrt_fraction(n: Fraction) -> Option<Fraction> {
     let mut prev;
@@ -68,5 +67,17 @@ fn benchmark_f64(c: &mut Criterion) {
     );
 }
 
-criterion_group!(benches, benchmark_fraction, benchmark_f64);
+fn benchmark_new(c: &mut Criterion) {
+    c.bench_function(
+        "Fraction::new", 
+        |b| b.iter(
+            || Fraction::new(
+                black_box(rand::random_range(i32::MIN..i32::MAX)), 
+                black_box(rand::random_range(1..i32::MAX))
+            )
+        )
+    );
+}
+
+criterion_group!(benches, benchmark_fraction, benchmark_f64, benchmark_new);
 criterion_main!(benches);
\ No newline at end of file
@@ -26,11 +26,15 @@
 //! - 在结果溢出时候使用 shrink 将结果转化为范围内的最接近结果的最简分数
 
 use std::{
-    cmp::Ordering, fmt::{self, Display, Formatter}, hash::{Hash, Hasher}, i32, ops::{
+    cmp::Ordering, fmt::{self, Display, Formatter}, hash::{Hash, Hasher}, str::FromStr, iter::{Product, Sum}, ops::{
         Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign 
     }
 };
 
+// shrink 实际进行近似的次数，仅在启用 `stats` feature 时统计
+#[cfg(feature = "stats")]
+static SHRINK_APPROXIMATIONS: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
 /// 分数类型，使用 `i32` 存储分子分母
 ///
 /// 维护最简分数形式，支持与基本数值类型的互操作
@@ -54,7 +58,7 @@ use std::{
 /// assert!(nan != nan); // NaN 不满足自反性
 /// ```
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Type {
     Normal,
     Infinity,
@@ -70,9 +74,31 @@ pub enum ConversionError {
     InfiniteConversion, 
 }
 
+/// 比较结果，见 [`Fraction::compare`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Less, 
+    Equal, 
+    Greater, 
+    /// 至少一方为 `NAN`，无法比较
+    Unordered, 
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseFractionError {
+    Empty, 
+    InvalidFormat, 
+    InvalidInteger, 
+}
+
+// 质因数分解，每项为 (质数, 指数)
+type Factors = Vec<(i32, i32)>;
+
 #[derive(Debug, Clone, Copy, Eq)]
 pub struct Fraction {
     nume: i32,
+    // 不变量：Normal 与 Zero 的分母恒为正，符号由分子表示
+    // 所有构造途径都须保证这一点，部分方法（如 reciprocal）依赖于此
     deno: i32, 
     frac_type: Type, 
 }
@@ -82,12 +108,24 @@ impl Fraction {
     pub const NEG_INFINITY: Self = Self { nume: i32::MIN, deno: 1, frac_type: Type::NegInfinity };
     pub const NAN: Self = Self { nume: 0, deno: 0, frac_type: Type::NaN };
     pub const ZERO: Self = Self { nume: 0, deno: 1, frac_type: Type::Zero };
+    pub const ONE: Self = Self { nume: 1, deno: 1, frac_type: Type::Normal };
+    pub const HALF: Self = Self { nume: 1, deno: 2, frac_type: Type::Normal };
+    pub const TWO: Self = Self { nume: 2, deno: 1, frac_type: Type::Normal };
 
     pub const MAX: Self = Self { nume: i32::MAX - 1, deno: 1, frac_type: Type::Normal};
     pub const MIN: Self = Self { nume: i32::MIN + 1, deno: 1, frac_type: Type::Normal};
     pub const MIN_POSITIVE: Self = Self { nume: 1, deno: i32::MAX, frac_type: Type::Normal};
+    /// 圆周率 π 在 `i32` 范围内的最佳有理近似，误差约 `3.1e-18`
+    pub const PI: Self = Self { nume: 1068966896, deno: 340262731, frac_type: Type::Normal };
+
+    const LIMITER: u128 = i32::MAX as u128;
 
-    const LIMITER: u64 = i32::MAX as u64;
+    // Unicode 中的分数字符 (U+00BC..U+00BE, U+2150..U+215E)
+    const VULGAR_FRACTIONS: [(char, i32, i32); 18] = [
+        ('¼', 1, 4), ('½', 1, 2), ('¾', 3, 4), ('⅐', 1, 7), ('⅑', 1, 9), ('⅒', 1, 10), 
+        ('⅓', 1, 3), ('⅔', 2, 3), ('⅕', 1, 5), ('⅖', 2, 5), ('⅗', 3, 5), ('⅘', 4, 5), 
+        ('⅙', 1, 6), ('⅚', 5, 6), ('⅛', 1, 8), ('⅜', 3, 8), ('⅝', 5, 8), ('⅞', 7, 8)
+    ];
 
     /// 创建新分数，自动化简为最简形式
     ///
@@ -120,7 +158,13 @@ impl Fraction {
         
                 let (nume, deno) = (nume.unsigned_abs() as u64, deno.unsigned_abs() as u64);
                 let gcd_val = Self::gcd(nume, deno);
-                let (nume, deno) = Self::shrink(nume / gcd_val, deno / gcd_val);
+                let (nume, deno) = (nume / gcd_val, deno / gcd_val);
+                // 化简后通常已在范围内，此时无需 shrink
+                let (nume, deno) = if nume <= Self::LIMITER as u64 && deno <= Self::LIMITER as u64 {
+                    (nume as u32, deno as u32)
+                } else {
+                    Self::shrink(nume as u128, deno as u128)
+                };
                 let (nume, deno) = (nume as i32 * sign, deno as i32);
         
                 Self {
@@ -132,7 +176,378 @@ impl Fraction {
         }
     }
 
-    fn determine_frac_type(nume: i32, deno: i32) -> Type {
+    /// 创建分数，结果不能精确表示为有限值时返回错误
+    ///
+    /// `Fraction::new` 会将 `(x, 0)` 转换为无穷或 `NAN`，将化简后超出范围的值 shrink 近似，
+    /// 并将化简后为 `i32::MAX/1` 的值视为 `INFINITY`。处理不可信的输入时可以使用本方法拒绝这些情况
+    ///
+    /// # 参数
+    /// - `nume`: 分子
+    /// - `deno`: 分母
+    ///
+    /// # 返回值
+    /// `Result<Self, ConversionError>`：
+    /// - `Err(InfiniteConversion)`: 分母为零且分子不为零
+    /// - `Err(NaNConversion)`: 分子分母均为零
+    /// - `Err(OutOfRangeError)`: 化简后超出范围，或与 `INFINITY`/`NEG_INFINITY` 的内部表示冲突
+    /// - 其余情况返回 `Ok`，与 `Fraction::new` 的结果相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ConversionError};
+    /// 
+    /// assert_eq!(Fraction::new_checked(4, -6), Ok(Fraction::new(-2, 3)));
+    /// assert_eq!(Fraction::new_checked(i32::MAX, 2), Ok(Fraction::new(i32::MAX, 2)));
+    /// assert_eq!(Fraction::new_checked(1, 0), Err(ConversionError::InfiniteConversion));
+    /// assert_eq!(Fraction::new_checked(0, 0), Err(ConversionError::NaNConversion));
+    /// assert_eq!(Fraction::new_checked(i32::MAX, 1), Err(ConversionError::OutOfRangeError));
+    /// ```
+    pub fn new_checked(nume: i32, deno: i32) -> Result<Self, ConversionError> {
+        match (nume, deno) {
+            (0, 0) => return Err(ConversionError::NaNConversion), 
+            (_, 0) => return Err(ConversionError::InfiniteConversion), 
+            _ => {}
+        }
+
+        let positive = (nume >= 0) == (deno > 0);
+        let (u_nume, u_deno) = (nume.unsigned_abs(), deno.unsigned_abs());
+        let gcd = Self::gcd(u_nume, u_deno);
+        let (u_nume, u_deno) = (u_nume / gcd, u_deno / gcd);
+        // i32::MIN 的绝对值超出范围；i32::MAX/1 为 INFINITY
+        let limit = Self::LIMITER as u32;
+        if u_nume > limit || u_deno > limit || (positive && u_nume == limit && u_deno == 1) {
+            return Err(ConversionError::OutOfRangeError);
+        }
+        Ok(Self::new(nume, deno))
+    }
+
+    /// 不化简，直接以给定的分子分母创建分数
+    ///
+    /// 仅根据分子分母确定类型，不进行化简、符号规范化与 shrink，可在常量上下文中使用。
+    /// 结果可能不满足内部不变量（见 [`validate_invariants`](Self::validate_invariants)），
+    /// 进行比较、哈希或运算之前须调用 [`reduce_in_place`](Self::reduce_in_place)。
+    /// 适合逐步构造分子分母、最后统一化简一次的场合
+    ///
+    /// # 参数
+    /// - `nume`: 分子
+    /// - `deno`: 分母
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let mut a = Fraction::new_unchecked(4, -6);
+    /// assert!(!a.validate_invariants());
+    /// a.reduce_in_place();
+    /// assert_eq!(a, Fraction::new(-2, 3));
+    /// ```
+    pub const fn new_unchecked(nume: i32, deno: i32) -> Self {
+        Self { nume, deno, frac_type: Self::determine_frac_type(nume, deno) }
+    }
+
+    /// 原地化简，使分数重新满足内部不变量
+    ///
+    /// 重新进行化简、符号规范化与 shrink，并更新类型，结果与以当前分子分母调用 `Fraction::new` 相同。
+    /// 对已经是最简形式的分数没有影响
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let mut a = Fraction::new_unchecked(6, 3);
+    /// a.reduce_in_place();
+    /// assert_eq!(a, Fraction::from(2));
+    ///
+    /// let mut b = Fraction::new_unchecked(0, -5);
+    /// b.reduce_in_place();
+    /// assert_eq!(b, Fraction::ZERO);
+    /// ```
+    pub fn reduce_in_place(&mut self) {
+        *self = Self::new(self.nume, self.deno);
+    }
+
+    /// 判断存储的分子分母是否互质
+    ///
+    /// 只检查 `gcd(|nume|, |deno|) == 1`，不检查符号与类型（完整的检查见 [`validate_invariants`](Self::validate_invariants)）。
+    /// 正常构造的分数总是满足该条件，可在 [`new_unchecked`](Self::new_unchecked) 或反序列化之后作为断言使用
+    ///
+    /// # 返回值
+    /// `bool`，`NAN` 的内部表示 `0/0` 视为 `true`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(4, 6).is_lowest_terms());
+    /// assert!(!Fraction::new_unchecked(4, 6).is_lowest_terms());
+    /// assert!(Fraction::new_unchecked(2, -3).is_lowest_terms());
+    /// assert!(!Fraction::new_unchecked(0, 5).is_lowest_terms());
+    /// ```
+    pub fn is_lowest_terms(&self) -> bool {
+        self.frac_type == Type::NaN || Self::gcd(self.nume.unsigned_abs(), self.deno.unsigned_abs()) == 1
+    }
+
+    /// 化简为最简形式
+    ///
+    /// 与 [`reduce_in_place`](Self::reduce_in_place) 相同，但返回新的分数
+    ///
+    /// # 返回值
+    /// `Self`，与以存储的分子分母调用 `Fraction::new` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new_unchecked(4, -6).to_lowest_terms(), Fraction::new(-2, 3));
+    /// assert!(Fraction::new_unchecked(10, 4).to_lowest_terms().is_lowest_terms());
+    /// ```
+    pub fn to_lowest_terms(self) -> Self {
+        Self::new(self.nume, self.deno)
+    }
+
+    /// 由显式符号与无符号的分子分母创建分数，自动化简为最简形式
+    ///
+    /// 分子可使用完整的 `u32` 范围，超出 `i32` 范围时使用 shrink 近似
+    ///
+    /// # 参数
+    /// - `sign`: 符号，正数为正，负数为负，零为零
+    /// - `nume`: 分子的绝对值
+    /// - `deno`: 分母的绝对值
+    ///
+    /// # 特殊值
+    /// - `sign` 为零或 `nume` 为零时返回 `ZERO`（分母为零时返回 `NAN`）
+    /// - 分母为零时按符号返回 `INFINITY`/`NEG_INFINITY`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_parts(-1, 4, 6), Fraction::new(-2, 3));
+    /// assert_eq!(Fraction::from_parts(1, 4, 6), Fraction::new(2, 3));
+    /// assert!(Fraction::from_parts(0, 4, 6).is_zero());
+    /// assert!(Fraction::from_parts(0, 4, 0).is_nan());
+    /// assert!(Fraction::from_parts(-1, 4, 0).is_neg_infinity());
+    ///
+    /// // 超出 i32 范围的分子
+    /// assert_eq!(Fraction::from_parts(-1, 3_000_000_000, 2), Fraction::from(-1_500_000_000));
+    /// ```
+    pub fn from_parts(sign: i8, nume: u32, deno: u32) -> Self {
+        Self::from_unsigned(sign as i32, nume as u128, deno as u128)
+    }
+
+    /// 由 `u64` 的分子分母与显式符号创建分数
+    ///
+    /// 先化简，化简后仍超出 `i32` 范围时使用 shrink 近似。
+    /// 因此像 `6000000000 / 4000000000` 这样可以约分到范围内的比值会得到精确结果
+    ///
+    /// # 参数
+    /// - `nume`: 分子的绝对值
+    /// - `deno`: 分母的绝对值
+    /// - `sign`: 符号，正数为正，负数为负，零为零
+    ///
+    /// # 返回值
+    /// `Self`，特殊值的规则与 [`from_parts`](Self::from_parts) 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_ratio_u64(6_000_000_000, 4_000_000_000, -1), Fraction::new(-3, 2));
+    /// assert_eq!(Fraction::from_ratio_u64(u64::MAX, 1, 1), Fraction::INFINITY);
+    /// assert!(Fraction::from_ratio_u64(1, 0, -1).is_neg_infinity());
+    /// ```
+    pub fn from_ratio_u64(nume: u64, deno: u64, sign: i8) -> Self {
+        Self::from_unsigned(sign as i32, nume as u128, deno as u128)
+    }
+
+    /// 化简并 shrink `nume / deno`，同时返回近似的误差 `近似值 - 精确值`
+    ///
+    /// 误差以 `i128` 精确计算后转换为 `f64`。shrink 的误差通常远小于 `MIN_POSITIVE`，
+    /// 若以分数表示会再次被 shrink 为零，因此使用 `f64` 返回
+    ///
+    /// # 参数
+    /// - `nume`: 分子
+    /// - `deno`: 分母
+    ///
+    /// # 返回值
+    /// `(Self, f64)`，结果与 [`from_ratio_u64`](Self::from_ratio_u64) 相同，误差为 `0.0` 表示精确：
+    /// - 结果为 `INFINITY`（超出范围）时误差为 `f64::INFINITY`
+    /// - `deno` 为零时误差为 `f64::NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::shrink_with_error(6, 4), (Fraction::new(3, 2), 0.0));
+    ///
+    /// let (approx, error) = Fraction::shrink_with_error(10_000_000_019, 3_000_000_001);
+    /// assert!(error != 0.0 && error.abs() < 1e-17);
+    /// assert!((f64::from(approx) - error - 10_000_000_019f64 / 3_000_000_001f64).abs() < 1e-15);
+    /// ```
+    pub fn shrink_with_error(nume: u64, deno: u64) -> (Self, f64) {
+        let approx = Self::from_ratio_u64(nume, deno, 1);
+        let error = match approx.frac_type {
+            _ if deno == 0 => f64::NAN, 
+            Type::Normal | Type::Zero => {
+                // p/q - n/d = (pd - nq) / qd，分子分母均小于 2^95
+                let (p, q) = (approx.nume as i128, approx.deno as i128);
+                let (n, d) = (nume as i128, deno as i128);
+                (p * d - n * q) as f64 / (q * d) as f64
+            }, 
+            _ => f64::INFINITY
+        };
+        (approx, error)
+    }
+
+    /// 由 `i32` 创建分数，值与特殊值的内部表示冲突时返回错误
+    ///
+    /// `i32::MAX` 与 `i32::MIN` 分别是 `INFINITY` 与 `NEG_INFINITY` 的内部表示，
+    /// `Fraction::from` 会将它们静默转换为 `INFINITY` 与 `NAN`，本方法改为返回错误
+    ///
+    /// # 参数
+    /// - `v`: 整数
+    ///
+    /// # 返回值
+    /// `Result<Self, ConversionError>`，`v` 为 `i32::MAX` 或 `i32::MIN` 时返回 `Err(OutOfRangeError)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ConversionError};
+    /// 
+    /// assert_eq!(Fraction::try_from_i32(42), Ok(Fraction::from(42)));
+    /// assert_eq!(Fraction::try_from_i32(i32::MAX), Err(ConversionError::OutOfRangeError));
+    /// assert_eq!(Fraction::try_from_i32(i32::MIN), Err(ConversionError::OutOfRangeError));
+    /// ```
+    pub fn try_from_i32(v: i32) -> Result<Self, ConversionError> {
+        match v {
+            i32::MAX | i32::MIN => Err(ConversionError::OutOfRangeError), 
+            _ => Ok(Self::from(v))
+        }
+    }
+
+    /// 由整数创建分数，超出范围时饱和为 `MAX`/`MIN`
+    ///
+    /// 与 `TryFrom` 不同，超出范围时不返回错误。
+    /// `i32::MAX` 与 `i32::MIN` 是特殊值的内部表示，同样视为超出范围
+    ///
+    /// # 参数
+    /// - `v`: 可转换为 `i128` 的整数
+    ///
+    /// # 返回值
+    /// `Self`，总是有限值
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::saturating_from(-42i64), Fraction::from(-42));
+    /// assert_eq!(Fraction::saturating_from(i64::MAX), Fraction::MAX);
+    /// assert_eq!(Fraction::saturating_from(i64::MIN), Fraction::MIN);
+    /// assert_eq!(Fraction::saturating_from(u64::MAX), Fraction::MAX);
+    /// ```
+    pub fn saturating_from<T: Into<i128>>(v: T) -> Self {
+        let v = v.into().clamp(Self::MIN.nume as i128, Self::MAX.nume as i128);
+        Self::from(v as i32)
+    }
+
+    /// 由 `f64` 的精确值创建分数，不做任何近似
+    ///
+    /// 有限的 `f64` 总是形如 `m / 2^k` 的二进分数，仅当其最简形式的分子分母都在范围内时成功。
+    /// 例如 `0.1` 的精确值为 `3602879701896397/36028797018963968`，因此返回 `None`
+    ///
+    /// # 参数
+    /// - `x`: 浮点数
+    ///
+    /// # 返回值
+    /// `Option<Self>`，`NAN`、无穷或超出范围时返回 `None`，`±0.0` 返回 `ZERO`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_f64_exact(0.75), Some(Fraction::new(3, 4)));
+    /// assert_eq!(Fraction::from_f64_exact(-3.0), Some(Fraction::from(-3)));
+    /// assert_eq!(Fraction::from_f64_exact(0.1), None);
+    /// assert_eq!(Fraction::from_f64_exact(f64::INFINITY), None);
+    /// ```
+    pub fn from_f64_exact(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let (sign, mant, exp) = Self::decompose_f64(x);
+        if mant == 0 {
+            return Some(Self::ZERO);
+        }
+
+        // 去掉尾数末尾的 0，使 mant / 2^-exp 为最简形式
+        let zeros = mant.trailing_zeros();
+        let (mant, exp) = (mant >> zeros, exp + zeros as i32);
+        let (nume, deno) = if exp >= 0 {
+            (mant.checked_shl(exp as u32).filter(|&n| n >> exp == mant)?, 1)
+        } else {
+            (mant, 1u64.checked_shl(exp.unsigned_abs())?)
+        };
+
+        // i32::MAX/1 是 INFINITY 的内部表示
+        let collides = sign > 0 && nume == Self::LIMITER as u64 && deno == 1;
+        if nume > Self::LIMITER as u64 || deno > Self::LIMITER as u64 || collides {
+            return None;
+        }
+        Some(Self::from_unsigned(sign, nume as u128, deno as u128))
+    }
+
+    fn from_unsigned(sign: i32, nume: u128, deno: u128) -> Self {
+        Self::from_unsigned_bounded(sign, nume, deno, Self::LIMITER)
+    }
+
+    // 与 from_unsigned 相同，但 shrink 时分母不超过 deno_limit
+    fn from_unsigned_bounded(sign: i32, nume: u128, deno: u128, deno_limit: u128) -> Self {
+        let sign = sign.signum();
+        if deno == 0 {
+            return match sign * (nume != 0) as i32 {
+                1 => Self::INFINITY, 
+                -1 => Self::NEG_INFINITY, 
+                _ => Self::NAN
+            };
+        }
+        if sign == 0 || nume == 0 {
+            return Self::ZERO;
+        }
+
+        let gcd_val = Self::gcd(nume, deno);
+        let (nume, deno) = Self::shrink_bounded(nume / gcd_val, deno / gcd_val, Self::LIMITER, deno_limit);
+        let (nume, deno) = (nume as i32 * sign, deno as i32);
+
+        Self {
+            nume, 
+            deno, 
+            frac_type: Self::determine_frac_type(nume, deno)
+        }
+    }
+
+    // 化简并 shrink，要求 deno > 0 且两者均小于 2^95
+    fn from_i128(nume: i128, deno: i128) -> Self {
+        Self::from_unsigned(nume.signum() as i32, nume.unsigned_abs(), deno as u128)
+    }
+
+    // 与 from_i128 相同，但需要 shrink 或结果为特殊值时返回 None，要求 deno > 0
+    fn checked_from_i128(nume: i128, deno: i128) -> Option<Self> {
+        let gcd_val = Self::gcd(nume.unsigned_abs(), deno as u128);
+        let (nume_abs, deno) = (nume.unsigned_abs() / gcd_val, deno as u128 / gcd_val);
+        if nume_abs > Self::LIMITER || deno > Self::LIMITER {
+            return None;
+        }
+        let (nume, deno) = (nume_abs as i32 * nume.signum() as i32, deno as i32);
+        match Self::determine_frac_type(nume, deno) {
+            frac_type @ (Type::Normal | Type::Zero) => Some(Self { nume, deno, frac_type }), 
+            _ => None
+        }
+    }
+
+    const fn determine_frac_type(nume: i32, deno: i32) -> Type {
         if deno == 0 { 
             match nume.signum() {
                 1 => Type::Infinity,
@@ -171,12 +586,83 @@ impl Fraction {
         (b / gcd, a / gcd, gcd)
     }
 
-    fn shrink(nume: u64, deno: u64) -> (u32, u32) {
+    /// 扩展欧几里得算法，求最大公约数与 Bézout 系数
+    ///
+    /// 返回 `(g, x, y)`，满足 `a * x + b * y == g`，其中 `g` 为 `a` 与 `b` 的最大公约数（非负）。
+    /// 可用于求模逆元等
+    ///
+    /// # 参数
+    /// - `a`, `b`: 两个整数
+    ///
+    /// # Panics
+    /// 最大公约数为 `2^31` 时（`a`、`b` 均为 `0` 或 `i32::MIN` 且不全为 `0`）结果超出 `i32` 范围，触发 panic
+    ///
+    /// # 返回值
+    /// `(i32, i32, i32)`，`gcd_extended(0, 0)` 为 `(0, 1, 0)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let (g, x, y) = Fraction::gcd_extended(240, 46);
+    /// assert_eq!(g, 2);
+    /// assert_eq!(240 * x + 46 * y, g);
+    ///
+    /// // 3 在模 7 下的逆元
+    /// let (_, inverse, _) = Fraction::gcd_extended(3, 7);
+    /// assert_eq!((3 * inverse).rem_euclid(7), 1);
+    /// ```
+    pub fn gcd_extended(a: i32, b: i32) -> (i32, i32, i32) {
+        let (mut r_0, mut r_1) = (a as i64, b as i64);
+        let (mut x_0, mut x_1) = (1i64, 0i64);
+        let (mut y_0, mut y_1) = (0i64, 1i64);
+        while r_1 != 0 {
+            let q = r_0 / r_1;
+            (r_0, r_1) = (r_1, r_0 - q * r_1);
+            (x_0, x_1) = (x_1, x_0 - q * x_1);
+            (y_0, y_1) = (y_1, y_0 - q * y_1);
+        }
+        if r_0 < 0 {
+            (r_0, x_0, y_0) = (-r_0, -x_0, -y_0);
+        }
+
+        let narrow = |v: i64| i32::try_from(v).expect("gcd out of range");
+        (narrow(r_0), narrow(x_0), narrow(y_0))
+    }
+
+    // 输入需小于 2^95，否则中间结果可能溢出 i128
+    fn shrink(nume: u128, deno: u128) -> (u32, u32) {
+        Self::shrink_bounded(nume, deno, Self::LIMITER, Self::LIMITER)
+    }
+
+    // 寻找分子不超过 nume_limit、分母不超过 deno_limit 的最接近的分数
+    // 输入需为最简形式（或本身已在范围内）
+    fn shrink_bounded(nume: u128, deno: u128, nume_limit: u128, deno_limit: u128) -> (u32, u32) {
+        #[cfg(feature = "stats")]
+        if nume > nume_limit || deno > deno_limit {
+            SHRINK_APPROXIMATIONS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        let [(nume_1, deno_1), (nume_2, deno_2)] = Self::bounding_candidates(nume, deno, nume_limit, deno_limit);
+        // 小于最小正数的值 shrink 为 0
+        if nume_1 == 0 {
+            return (0, 1);
+        }
+
+        let d_1 = (nume_1 as i128 * deno as i128 - nume as i128 * deno_1 as i128).abs();
+        let d_2 = (nume_2 as i128 * deno as i128 - nume as i128 * deno_2 as i128).abs();
+
+        if d_1 * deno_2 as i128 <= d_2 * deno_1 as i128 { (nume_1 as u32, deno_1 as u32) } else { (nume_2 as u32, deno_2 as u32) }
+    }
+
+    // 范围内从两侧夹住 nume / deno 的两个分数：最后一个渐进分数与对应的中间分数，
+    // 最接近的分数必为其中之一。已在范围内、超出上限或趋于 0 时两者相同
+    fn bounding_candidates(nume: u128, deno: u128, nume_limit: u128, deno_limit: u128) -> [(u128, u128); 2] {
         let nume_abs = nume;
         let deno_abs = deno;
             
-        if nume_abs <= Self::LIMITER && deno_abs <= Self::LIMITER {
-            return (nume as u32, deno as u32);
+        if nume_abs <= nume_limit && deno_abs <= deno_limit {
+            return [(nume, deno); 2];
         }
 
         let (mut p_0, mut q_0, mut p_1, mut q_1) = (0, 1, 1, 0); // [0, +inf)
@@ -186,7 +672,7 @@ impl Fraction {
             let p_2 = p_0 + q * p_1;
             let q_2 = q_0 + q * q_1;
             
-            if p_2 > Self::LIMITER || q_2 > Self::LIMITER {
+            if p_2 > nume_limit || q_2 > deno_limit {
                 break;
             }
 
@@ -194,85 +680,387 @@ impl Fraction {
             (nume, deno) = (deno, nume - q * deno);
         }
         let (k_q, k_p) = {
-            let k_q = if q_1 != 0 {
-                (Self::LIMITER - q_0) / q_1
-            } else {
-                return (i32::MAX as u32, 1); // q_1 == 0 <=> inf
+            let k_q = match (deno_limit - q_0).checked_div(q_1) {
+                Some(k_q) => k_q,
+                None => return [(nume_limit, 1); 2], // q_1 == 0 <=> inf
             };
         
-            let k_p = if p_1 != 0 {
-                (Self::LIMITER - p_0) / p_1
-            } else {
-                return (0, 1); // p_1 == 0 <=> 0
-            };
+            // p_1 == 0 <=> 0，此时分子不限制 k
+            let k_p = (nume_limit - p_0).checked_div(p_1).unwrap_or(u128::MAX);
         
             (k_q, k_p)
         };
-        let k = k_q.min(k_p).max(0);
+        let k = k_q.min(k_p);
 
-        let (nume_1, deno_1) = (p_1, q_1);
-        let (nume_2, deno_2) = (p_0 + k * p_1, q_0 + k * q_1);
-        
-        let d_1 = (nume_1 as i128 * deno_abs as i128 - nume_abs as i128 * deno_1 as i128).abs();
-        let d_2 = (nume_2 as i128 * deno_abs as i128 - nume_abs as i128 * deno_2 as i128).abs();
+        [(p_1, q_1), (p_0 + k * p_1, q_0 + k * q_1)]
+    }
 
-        if d_1 * deno_2 as i128 <= d_2 * deno_1 as i128 { (nume_1 as u32, deno_1 as u32) } else { (nume_2 as u32, deno_2 as u32) }
+    // 两个候选中更接近 nume/deno 的一个，距离相等时取分母较小者
+    fn closest_candidate(nume: u128, deno: u128, [(nume_1, deno_1), (nume_2, deno_2)]: [(u128, u128); 2]) -> (u128, u128) {
+        let d_1 = (nume_1 as i128 * deno as i128 - nume as i128 * deno_1 as i128).abs();
+        let d_2 = (nume_2 as i128 * deno as i128 - nume as i128 * deno_2 as i128).abs();
+        match (d_1 * deno_2 as i128).cmp(&(d_2 * deno_1 as i128)) {
+            Ordering::Less => (nume_1, deno_1), 
+            Ordering::Greater => (nume_2, deno_2), 
+            Ordering::Equal if deno_1 <= deno_2 => (nume_1, deno_1), 
+            Ordering::Equal => (nume_2, deno_2)
+        }
     }
 
-    /// 获取符号
+    /// 检查内部表示是否自洽，用于测试与调试
+    ///
+    /// 类型与分子分母是两份冗余的信息，本方法检查两者是否一致：
+    /// - `frac_type` 与由 `(nume, deno)` 推断出的类型相同
+    /// - 特殊值与对应常量的内部表示完全相同
+    /// - 有限值的分母为正，且为最简形式
+    ///
+    /// 所有公开的构造方法与运算都应保证返回 `true`
     ///
     /// # 返回值
-    /// `Self`
+    /// `bool`
     ///
     /// # 示例
     /// ```
     /// # use fraction::Fraction;
     /// 
-    /// let a1 = Fraction::new(2, 3);
-    /// assert_eq!(a1.sign(), Fraction::from(1));
-    /// let a2 = Fraction::new(-2, 3);
-    /// assert_eq!(a2.sign(), Fraction::from(-1));
-    ///
-    /// let zero = Fraction::ZERO;
-    /// assert!(zero.sign().is_zero());
-    /// let nan = Fraction::NAN;
-    /// assert!(nan.sign().is_nan());
-    /// let inf = Fraction::INFINITY;
-    /// assert_eq!(inf.sign(), Fraction::from(1));
-    /// let neg_inf = Fraction::NEG_INFINITY;
-    /// assert_eq!(neg_inf.sign(), Fraction::from(-1));
+    /// assert!(Fraction::new(4, -6).validate_invariants());
+    /// assert!(Fraction::INFINITY.validate_invariants());
+    /// assert!((Fraction::MAX * 2).validate_invariants());
     /// ```
-    pub fn sign(&self) -> Self {
+    pub fn validate_invariants(&self) -> bool {
+        if self.deno < 0 || self.frac_type != Self::determine_frac_type(self.nume, self.deno) {
+            return false;
+        }
         match self.frac_type {
-            Type::NaN => Self::NAN,
-            Type::Zero => Self::ZERO,
-            Type::Infinity => Self { 
-                nume: 1, 
-                deno: 1, 
-                frac_type: Type::Normal
-            },
-            Type::NegInfinity => Self { 
-                nume: -1, 
-                deno: 1, 
-                frac_type: Type::Normal
-            },
-            Type::Normal => if self.nume >= 0 { Self { 
-                nume: 1, 
-                deno: 1, 
-                frac_type: Type::Normal
-            } } else { Self { 
-                nume: -1, 
-                deno: 1, 
-                frac_type: Type::Normal
-            } }
+            Type::Infinity => (self.nume, self.deno) == (Self::INFINITY.nume, Self::INFINITY.deno), 
+            Type::NegInfinity => (self.nume, self.deno) == (Self::NEG_INFINITY.nume, Self::NEG_INFINITY.deno), 
+            Type::NaN => (self.nume, self.deno) == (Self::NAN.nume, Self::NAN.deno), 
+            Type::Zero => self.deno == 1, 
+            Type::Normal => self.deno > 0 && Self::gcd(self.nume.unsigned_abs(), self.deno as u32) == 1
         }
     }
-    
-    fn i32_sign(&self) -> i32 {
-        match self.frac_type {
-            Type::NaN => 0,
-            Type::Zero => 0,
-            Type::Infinity => 1,
+
+    /// 获取最简形式的分子，符号由分子表示
+    ///
+    /// 特殊值返回其内部表示：`INFINITY` 为 `i32::MAX`，`NEG_INFINITY` 为 `i32::MIN`，
+    /// `NAN` 与 `ZERO` 为 `0`
+    ///
+    /// # 返回值
+    /// `i32`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(4, -6);
+    /// assert_eq!(a.numerator(), -2);
+    /// ```
+    pub const fn numerator(&self) -> i32 {
+        self.nume
+    }
+
+    /// 获取最简形式的分母
+    ///
+    /// 有限值的分母恒为正，`INFINITY`/`NEG_INFINITY` 为 `1`，`NAN` 为 `0`
+    ///
+    /// # 返回值
+    /// `i32`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(4, -6);
+    /// assert_eq!(a.denominator(), 3);
+    /// assert_eq!(Fraction::NAN.denominator(), 0);
+    /// ```
+    pub const fn denominator(&self) -> i32 {
+        self.deno
+    }
+
+    /// 将分数打包为一个 `u64`，适合大量分数的紧凑存储
+    ///
+    /// 高 32 位为分子，低 32 位为分母。特殊值使用其内部表示，
+    /// 即 `INFINITY` 为 `i32::MAX/1`，`NEG_INFINITY` 为 `i32::MIN/1`，`NAN` 为 `0/0`，
+    /// 这些组合不会与任何有限值冲突
+    ///
+    /// # 返回值
+    /// `u64`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(-2, 3);
+    /// assert_eq!(a.pack(), 0xFFFF_FFFE_0000_0003);
+    /// assert_eq!(Fraction::unpack(a.pack()), a);
+    /// ```
+    pub fn pack(&self) -> u64 {
+        ((self.nume as u32 as u64) << 32) | self.deno as u32 as u64
+    }
+
+    /// 由 [`pack`](Self::pack) 的结果还原分数
+    ///
+    /// 对 `pack` 的结果总是无损还原。其他任意输入按 `Fraction::new(高 32 位, 低 32 位)` 处理
+    ///
+    /// # 参数
+    /// - `packed`: 打包后的 `u64`
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::unpack(Fraction::NAN.pack()).is_nan());
+    /// assert!(Fraction::unpack(Fraction::NEG_INFINITY.pack()).is_neg_infinity());
+    /// assert_eq!(Fraction::unpack((4 << 32) | 6), Fraction::new(2, 3));
+    /// ```
+    pub fn unpack(packed: u64) -> Self {
+        Self::new((packed >> 32) as u32 as i32, packed as u32 as i32)
+    }
+
+    /// 转换为 `f64`，与 `f64::from` 相同
+    ///
+    /// # 返回值
+    /// `f64`，特殊值转换为对应的 `INFINITY`/`NEG_INFINITY`/`NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(3, 4).to_f64(), 0.75);
+    /// assert_eq!(Fraction::NEG_INFINITY.to_f64(), f64::NEG_INFINITY);
+    /// ```
+    pub fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    /// 转换为 `f32`，与 `f32::from` 相同
+    ///
+    /// # 返回值
+    /// `f32`，特殊值转换为对应的 `INFINITY`/`NEG_INFINITY`/`NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-1, 8).to_f32(), -0.125);
+    /// assert!(Fraction::NAN.to_f32().is_nan());
+    /// ```
+    pub fn to_f32(self) -> f32 {
+        f32::from(self)
+    }
+
+    /// 转换为最接近的 `f64`（就近舍入，平局取偶）
+    ///
+    /// 保证结果是精确有理数值正确舍入后的 `f64`。分子分母均为 `i32`，转换为 `f64` 时没有误差，
+    /// 而 IEEE 754 的除法本身是正确舍入的，因此只会舍入一次；本方法与 `to_f64` 结果相同，
+    /// 用于明确表达对舍入的依赖
+    ///
+    /// # 返回值
+    /// `f64`，特殊值转换为对应的 `INFINITY`/`NEG_INFINITY`/`NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).to_f64_rounded(), 1.0 / 3.0);
+    /// assert_eq!(Fraction::new(-2000000028, 1999999915).to_f64_rounded(), -2000000028.0 / 1999999915.0);
+    /// ```
+    pub fn to_f64_rounded(self) -> f64 {
+        match self.frac_type {
+            Type::Normal => self.nume as f64 / self.deno as f64,
+            _ => f64::from(self)
+        }
+    }
+
+    /// 转换为最接近的 `f32`（就近舍入，平局取偶）
+    ///
+    /// `f32::from` 先把分子分母分别舍入为 `f32` 再相除，共舍入三次，可能与正确结果相差一个 ulp；
+    /// 先正确舍入为 `f64` 再转换为 `f32` 同样不可靠：精确值略大于两个 `f32` 的中点时，
+    /// 可能先被舍入到中点上，再按平局取偶向下舍入。
+    /// 本方法以整数除法直接求出 24 位有效数字的商，余数决定舍入方向（相当于舍入位与粘滞位），只舍入一次
+    ///
+    /// # 返回值
+    /// `f32`，特殊值转换为对应的 `INFINITY`/`NEG_INFINITY`/`NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let x = Fraction::new(2000000028, 1999999915);
+    /// assert_eq!(x.to_f32_rounded(), 1.0);
+    /// assert_eq!(x.to_f32(), 1.0000001);
+    ///
+    /// // 经由 f64 会舍入到 1.0
+    /// let y = Fraction::new(1073741887, 1073741823);
+    /// assert_eq!(y.to_f32_rounded(), 1.0000001);
+    /// assert_eq!(y.to_f64_rounded() as f32, 1.0);
+    /// ```
+    pub fn to_f32_rounded(self) -> f32 {
+        if !matches!(self.frac_type, Type::Normal) {
+            return f32::from(self);
+        }
+
+        // |self| 在 [2^-31, 2^31) 内，取 shift 使 nume * 2^shift / deno 在 [2^23, 2^24) 内
+        let (nume, deno) = (self.nume.unsigned_abs() as u128, self.deno as u128);
+        let mut shift = 24 - (nume.ilog2() as i32 - deno.ilog2() as i32);
+        let scaled = |shift: i32| if shift >= 0 { (nume << shift, deno) } else { (nume, deno << -shift) };
+        let (mut n, mut d) = scaled(shift);
+        if n / d >= 1 << 24 {
+            shift -= 1;
+            (n, d) = scaled(shift);
+        }
+
+        let (q, r) = (n / d, n % d);
+        let mantissa = match (2 * r).cmp(&d) {
+            Ordering::Less => q, 
+            Ordering::Greater => q + 1, 
+            Ordering::Equal => q + q % 2
+        };
+        // mantissa 不超过 2^24，乘以 2 的幂是精确的；指数在 f32 的正规数范围内
+        let scale = f32::from_bits(((127 - shift) as u32) << 23);
+        let value = mantissa as f32 * scale;
+        if self.nume < 0 { -value } else { value }
+    }
+
+    /// 向零取整并饱和转换为 `i32`
+    ///
+    /// 与 `TryFrom` 不同，超出范围时不返回错误，行为与 `f64 as i32` 相同
+    ///
+    /// # 返回值
+    /// `i32`，`INFINITY` 为 `i32::MAX`，`NEG_INFINITY` 为 `i32::MIN`，`NAN` 为 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-7, 2).to_i32_saturating(), -3);
+    /// assert_eq!(Fraction::INFINITY.to_i32_saturating(), i32::MAX);
+    /// assert_eq!(Fraction::NAN.to_i32_saturating(), 0);
+    /// ```
+    pub fn to_i32_saturating(self) -> i32 {
+        self.trunc_saturating().clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// 向零取整并饱和转换为 `i64`
+    ///
+    /// # 返回值
+    /// `i64`，`INFINITY` 为 `i64::MAX`，`NEG_INFINITY` 为 `i64::MIN`，`NAN` 为 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 2).to_i64_saturating(), 3);
+    /// assert_eq!(Fraction::NEG_INFINITY.to_i64_saturating(), i64::MIN);
+    /// ```
+    pub fn to_i64_saturating(self) -> i64 {
+        self.trunc_saturating()
+    }
+
+    /// 向零取整并饱和转换为 `u32`
+    ///
+    /// # 返回值
+    /// `u32`，负数与 `NEG_INFINITY` 为 `0`，`INFINITY` 为 `u32::MAX`，`NAN` 为 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 2).to_u32_saturating(), 3);
+    /// assert_eq!(Fraction::from(-5).to_u32_saturating(), 0);
+    /// assert_eq!(Fraction::INFINITY.to_u32_saturating(), u32::MAX);
+    /// ```
+    pub fn to_u32_saturating(self) -> u32 {
+        self.trunc_saturating().clamp(0, u32::MAX as i64) as u32
+    }
+
+    // 向零取整，无穷饱和为 i64 的边界，NAN 为 0
+    fn trunc_saturating(self) -> i64 {
+        match self.frac_type {
+            Type::Infinity => i64::MAX, 
+            Type::NegInfinity => i64::MIN, 
+            Type::NaN | Type::Zero => 0, 
+            Type::Normal => (self.nume / self.deno) as i64
+        }
+    }
+
+    /// 获取符号
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a1 = Fraction::new(2, 3);
+    /// assert_eq!(a1.sign(), Fraction::from(1));
+    /// let a2 = Fraction::new(-2, 3);
+    /// assert_eq!(a2.sign(), Fraction::from(-1));
+    ///
+    /// let zero = Fraction::ZERO;
+    /// assert!(zero.sign().is_zero());
+    /// let nan = Fraction::NAN;
+    /// assert!(nan.sign().is_nan());
+    /// let inf = Fraction::INFINITY;
+    /// assert_eq!(inf.sign(), Fraction::from(1));
+    /// let neg_inf = Fraction::NEG_INFINITY;
+    /// assert_eq!(neg_inf.sign(), Fraction::from(-1));
+    /// ```
+    pub fn sign(&self) -> Self {
+        self.signum_const()
+    }
+
+    /// 获取符号，可在常量上下文中使用
+    ///
+    /// 与 [`sign`](Self::sign) 的结果相同
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// const NEG_ONE: Fraction = Fraction::MIN.signum_const();
+    /// assert_eq!(NEG_ONE, Fraction::from(-1));
+    /// assert_eq!(NEG_ONE, Fraction::MIN.sign());
+    /// ```
+    pub const fn signum_const(&self) -> Self {
+        match self.frac_type {
+            Type::NaN => Self::NAN,
+            Type::Zero => Self::ZERO,
+            Type::Infinity => Self { 
+                nume: 1, 
+                deno: 1, 
+                frac_type: Type::Normal
+            },
+            Type::NegInfinity => Self { 
+                nume: -1, 
+                deno: 1, 
+                frac_type: Type::Normal
+            },
+            Type::Normal => if self.nume >= 0 { Self { 
+                nume: 1, 
+                deno: 1, 
+                frac_type: Type::Normal
+            } } else { Self { 
+                nume: -1, 
+                deno: 1, 
+                frac_type: Type::Normal
+            } }
+        }
+    }
+    
+    const fn i32_sign(&self) -> i32 {
+        match self.frac_type {
+            Type::NaN => 0,
+            Type::Zero => 0,
+            Type::Infinity => 1,
             Type::NegInfinity => -1,
             Type::Normal => if self.nume > 0 { 1 } else { -1 }
         }
@@ -444,66 +1232,3282 @@ impl Fraction {
     /// let neg_inf = Fraction::NEG_INFINITY;
     /// assert!(!neg_inf.is_nan());
     /// ```
-    pub fn is_nan(&self) -> bool {
-        self.frac_type == Type::NaN
+    pub fn is_nan(&self) -> bool {
+        self.frac_type == Type::NaN
+    }
+
+    /// 非特数值（不包括零值）返回 true，否则返回 false
+    ///
+    /// # 返回值
+    /// `bool`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a1 = Fraction::new(2, 3);
+    /// assert!(a1.is_normal());
+    /// let a2 = Fraction::new(-2, 3);
+    /// assert!(a2.is_normal());
+    ///
+    /// let zero = Fraction::ZERO;
+    /// assert!(!zero.is_normal());
+    /// let nan = Fraction::NAN;
+    /// assert!(!nan.is_normal());
+    /// let inf = Fraction::INFINITY;
+    /// assert!(!inf.is_normal());
+    /// let neg_inf = Fraction::NEG_INFINITY;
+    /// assert!(!neg_inf.is_normal());
+    /// ```
+    pub fn is_normal(&self) -> bool {
+        self.frac_type == Type::Normal
+    }
+
+    /// 获取绝对值，保持特殊值语义
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(-2, 3);
+    /// assert_eq!(a.abs(), Fraction::new(2, 3));
+    ///
+    /// let inf = Fraction::INFINITY;
+    /// assert_eq!(inf.abs(), Fraction::INFINITY);
+    /// ```
+    pub fn abs(&self) -> Self {
+        match self.frac_type {
+            Type::NegInfinity | Type::Infinity => Self::INFINITY, 
+            Type::NaN => Self::NAN, 
+            Type::Zero => Self::ZERO, 
+            // MIN 的绝对值 i32::MAX/1 即为 INFINITY
+            _ if self.nume == Self::MIN.nume && self.deno == 1 => Self::INFINITY, 
+            _ => Self {
+                nume: self.nume.abs(), 
+                deno: self.deno, 
+                frac_type: self.frac_type
+            }
+        }
+    }
+
+    /// 设定符号，保持绝对值不变
+    ///
+    /// 与 `f64::copysign` 类似，但符号由 `bool` 给出。
+    /// `ZERO` 没有符号，`ZERO` 与 `NAN` 保持不变；无穷变为对应符号的无穷。
+    /// 与取反相同，将 `MIN` 设为正数得到 `INFINITY`
+    ///
+    /// # 参数
+    /// - `negative`: 是否为负数
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(2, 3).with_sign(true), Fraction::new(-2, 3));
+    /// assert_eq!(Fraction::new(-2, 3).with_sign(false), Fraction::new(2, 3));
+    /// assert_eq!(Fraction::INFINITY.with_sign(true), Fraction::NEG_INFINITY);
+    /// assert_eq!(Fraction::ZERO.with_sign(true), Fraction::ZERO);
+    /// ```
+    pub fn with_sign(self, negative: bool) -> Self {
+        match self.frac_type {
+            Type::NaN | Type::Zero => self, 
+            _ if self.is_negative() == negative => self, 
+            _ => -self
+        }
+    }
+
+    /// 保持 `self` 的符号，绝对值取 `mag` 的绝对值
+    ///
+    /// 即 `mag.abs().with_sign(self.is_negative())`，`ZERO` 与 `NAN` 视为正数。
+    /// 任一方为 `NAN` 时返回 `NAN`
+    ///
+    /// # 参数
+    /// - `mag`: 提供绝对值的数
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-1, 2).with_magnitude(Fraction::new(3, 4)), Fraction::new(-3, 4));
+    /// assert_eq!(Fraction::new(1, 2).with_magnitude(Fraction::new(-3, 4)), Fraction::new(3, 4));
+    /// assert_eq!(Fraction::from(-5).with_magnitude(Fraction::INFINITY), Fraction::NEG_INFINITY);
+    /// assert_eq!(Fraction::from(-5).with_magnitude(Fraction::ZERO), Fraction::ZERO);
+    /// ```
+    pub fn with_magnitude(self, mag: Self) -> Self {
+        if self.is_nan() {
+            return Self::NAN;
+        }
+        mag.abs().with_sign(self.is_negative())
+    }
+
+    /// 到最近整数的距离
+    ///
+    /// 即 `|self - round(self)|`，精确计算，结果总在 `[0, 1/2]` 内
+    ///
+    /// # 返回值
+    /// `Self`，整数返回 `ZERO`，无穷与 `NAN` 返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 3).dist_to_nearest_int(), Fraction::new(1, 3));
+    /// assert_eq!(Fraction::new(-7, 3).dist_to_nearest_int(), Fraction::new(1, 3));
+    /// assert_eq!(Fraction::new(5, 2).dist_to_nearest_int(), Fraction::new(1, 2));
+    /// assert!(Fraction::from(4).dist_to_nearest_int().is_zero());
+    /// assert!(Fraction::INFINITY.dist_to_nearest_int().is_nan());
+    /// ```
+    pub fn dist_to_nearest_int(&self) -> Self {
+        match self.frac_type {
+            Type::Zero => Self::ZERO, 
+            Type::Normal => {
+                // 余数与分母互质，因此结果已是最简形式
+                let rem = self.nume.rem_euclid(self.deno);
+                Self::new(rem.min(self.deno - rem), self.deno)
+            }, 
+            _ => Self::NAN
+        }
+    }
+
+    /// 获取倒数，保持特殊值语义
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(2, 3);
+    /// assert_eq!(a.reciprocal(), Fraction::new(3, 2));
+    ///
+    /// let inf = Fraction::INFINITY;
+    /// assert!(inf.reciprocal().is_zero());
+    ///
+    /// // i32::MAX 超出范围，与 1 / MIN_POSITIVE 相同
+    /// assert!(Fraction::MIN_POSITIVE.reciprocal().is_infinity());
+    /// ```
+    pub fn reciprocal(&self) -> Self {
+        self.reciprocal_const()
+    }
+
+    /// 获取倒数，与 [`reciprocal`](Self::reciprocal) 相同，命名与 `f64::recip` 一致
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-2, 3).recip(), Fraction::new(-3, 2));
+    /// assert!(Fraction::ZERO.recip().is_infinity());
+    /// ```
+    pub fn recip(&self) -> Self {
+        self.reciprocal_const()
+    }
+
+    /// 获取倒数，可在常量上下文中使用
+    ///
+    /// 与 [`reciprocal`](Self::reciprocal) 的结果相同
+    ///
+    /// # 返回值
+    /// `Self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// const TINY: Fraction = Fraction::MAX.reciprocal_const();
+    /// assert_eq!(TINY, Fraction::new(1, i32::MAX - 1));
+    /// assert_eq!(TINY, Fraction::MAX.reciprocal());
+    /// ```
+    pub const fn reciprocal_const(&self) -> Self {
+        match self.frac_type {
+            Type::Infinity => Self::ZERO, 
+            Type::NegInfinity => Self::ZERO, 
+            Type::NaN => Self::NAN, 
+            Type::Zero => Self::INFINITY, 
+            Type::Normal => {
+                // 分母恒为正，分子不会是 i32::MIN，unsigned_abs 不会溢出
+                let nume = self.deno * self.i32_sign();
+                let deno = self.nume.unsigned_abs() as i32;
+                // 如 MIN_POSITIVE 的倒数 i32::MAX/1 即为 INFINITY
+                match Self::determine_frac_type(nume, deno) {
+                    Type::Infinity => Self::INFINITY, 
+                    frac_type => Self { nume, deno, frac_type }
+                }
+            }
+        }
+    }
+
+    /// 获取倒数，结果无法精确表示时返回 `None`
+    ///
+    /// 与 [`reciprocal`](Self::reciprocal) 不同，`ZERO` 与 `NAN` 返回 `None` 而不是 `INFINITY`/`NAN`，
+    /// 倒数与 `INFINITY`/`NEG_INFINITY` 的内部表示冲突时（如 `MIN_POSITIVE`）也返回 `None`
+    ///
+    /// # 返回值
+    /// `Option<Self>`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-2, 3).checked_reciprocal(), Some(Fraction::new(-3, 2)));
+    /// assert_eq!(Fraction::INFINITY.checked_reciprocal(), Some(Fraction::ZERO));
+    /// assert_eq!(Fraction::ZERO.checked_reciprocal(), None);
+    /// assert_eq!(Fraction::NAN.checked_reciprocal(), None);
+    /// assert_eq!(Fraction::MIN_POSITIVE.checked_reciprocal(), None);
+    /// ```
+    pub fn checked_reciprocal(&self) -> Option<Self> {
+        match self.frac_type {
+            Type::Zero | Type::NaN => None, 
+            Type::Infinity | Type::NegInfinity => Some(Self::ZERO), 
+            Type::Normal => {
+                let (nume, deno) = (self.deno * self.i32_sign(), self.nume.abs());
+                match Self::determine_frac_type(nume, deno) {
+                    Type::Normal => Some(Self { nume, deno, frac_type: Type::Normal }), 
+                    _ => None
+                }
+            }
+        }
+    }
+
+    /// 整数次幂，结果无法精确表示时返回 `None`
+    ///
+    /// 结果超出 `i32` 范围时返回 `None`，而不是 shrink 近似或变为 `INFINITY`
+    ///
+    /// # 参数
+    /// - `exp`: 指数，负数表示倒数的幂
+    ///
+    /// # 返回值
+    /// `Option<Self>`：
+    /// - 任意有限值的 0 次幂为 `1`
+    /// - `ZERO` 的负数次幂、`NAN` 与无穷返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(2, 3).checked_pow(2), Some(Fraction::new(4, 9)));
+    /// assert_eq!(Fraction::new(2, 3).checked_pow(-3), Some(Fraction::new(27, 8)));
+    /// assert_eq!(Fraction::from(-2).checked_pow(3), Some(Fraction::from(-8)));
+    /// assert_eq!(Fraction::from(100000).checked_pow(2), None);
+    /// assert_eq!(Fraction::ZERO.checked_pow(-1), None);
+    /// ```
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        match self.frac_type {
+            Type::NaN | Type::Infinity | Type::NegInfinity => return None, 
+            Type::Zero => return match exp.signum() {
+                1 => Some(Self::ZERO), 
+                0 => Some(Self::from(1)), 
+                _ => None
+            }, 
+            Type::Normal => {}
+        }
+
+        let base = if exp < 0 { self.checked_reciprocal()? } else { self };
+        let exp = exp.unsigned_abs();
+        // 分子分母互质，幂仍互质，无需化简
+        let nume = base.nume.checked_pow(exp)?;
+        let deno = base.deno.checked_pow(exp)?;
+        match Self::determine_frac_type(nume, deno) {
+            Type::Normal => Some(Self { nume, deno, frac_type: Type::Normal }), 
+            _ => None
+        }
+    }
+
+    /// 整数次幂，溢出时饱和为 `MAX`/`MIN` 而不是无穷
+    ///
+    /// 能精确表示时与 [`checked_pow`](Self::checked_pow) 相同；否则以平方求幂计算，
+    /// 每次乘法按 `*` 的规则 shrink，结果为近似值。
+    /// - 绝对值超出 `MAX` 时按符号返回 `MAX` 或 `MIN`
+    /// - 绝对值小于 `MIN_POSITIVE` 时与 shrink 相同，变为 `ZERO`
+    ///
+    /// # 参数
+    /// - `exp`: 指数，负数表示倒数的幂
+    ///
+    /// # 返回值
+    /// `Self`：
+    /// - 任意非 `NAN` 值的 0 次幂为 `1`
+    /// - `ZERO` 的负数次幂饱和为 `MAX`
+    /// - 无穷按绝对值无穷大处理，正数次幂饱和为 `MAX`/`MIN`，负数次幂为 `ZERO`
+    /// - `NAN` 返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(2).saturating_pow(40), Fraction::MAX);
+    /// assert_eq!(Fraction::from(-2).saturating_pow(41), Fraction::MIN);
+    /// assert_eq!(Fraction::new(2, 3).saturating_pow(-3), Fraction::new(27, 8));
+    /// assert!(Fraction::new(1, 2).saturating_pow(40).is_zero());
+    /// ```
+    pub fn saturating_pow(self, exp: i32) -> Self {
+        if let Some(result) = self.checked_pow(exp) {
+            return result;
+        }
+        let negative = self.is_negative() && exp % 2 != 0;
+        let magnitude = match self.frac_type {
+            Type::NaN => return Self::NAN, 
+            _ if exp == 0 => return Self::from(1), 
+            Type::Infinity | Type::NegInfinity => if exp > 0 { Self::INFINITY } else { Self::ZERO }, 
+            Type::Zero => Self::INFINITY, 
+            Type::Normal => {
+                let mut base = if exp < 0 { self.abs().reciprocal() } else { self.abs() };
+                let mut exp = exp.unsigned_abs();
+                let mut result = Self::from(1);
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result *= base;
+                    }
+                    base *= base;
+                    exp >>= 1;
+                }
+                result
+            }
+        };
+
+        match (magnitude.frac_type, negative) {
+            (Type::Infinity, false) => Self::MAX, 
+            (Type::Infinity, true) => Self::MIN, 
+            (_, false) => magnitude, 
+            (_, true) => -magnitude
+        }
+    }
+
+    /// 分数次幂，仅当结果为整数时返回
+    ///
+    /// 对 `exp = p/q`，先对分子分母分别开精确的 `q` 次方根，再求 `p` 次幂，全程不做近似。
+    /// 负数只有奇数次方根
+    ///
+    /// # 参数
+    /// - `exp`: 指数
+    ///
+    /// # 返回值
+    /// `Option<i32>`：
+    /// - 结果为 `i32` 范围内的整数时返回 `Some`，任意有限值的 0 次幂为 `1`
+    /// - 结果不是整数、超出范围，`ZERO` 的负数次幂，或含有 `NAN`、无穷时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(8).ipow_frac(Fraction::new(1, 3)), Some(2));
+    /// assert_eq!(Fraction::from(-8).ipow_frac(Fraction::new(2, 3)), Some(4));
+    /// assert_eq!(Fraction::new(1, 4).ipow_frac(Fraction::new(-3, 2)), Some(8));
+    /// assert_eq!(Fraction::from(2).ipow_frac(Fraction::new(1, 2)), None);
+    /// ```
+    pub fn ipow_frac(self, exp: Self) -> Option<i32> {
+        match (self.frac_type, exp.frac_type) {
+            (Type::Normal | Type::Zero, Type::Zero) => return Some(1), 
+            (Type::Normal | Type::Zero, Type::Normal) => {}, 
+            _ => return None
+        }
+
+        let (p, q) = (exp.nume, exp.deno as u32);
+        if self.nume < 0 && q.is_multiple_of(2) {
+            return None;
+        }
+        let a = Self::nth_root(self.nume.unsigned_abs() as u128, q)?;
+        let b = Self::nth_root(self.deno as u128, q)?;
+        let (nume, deno) = if p > 0 { (a, b) } else { (b, a) };
+        if deno != 1 {
+            return None;
+        }
+
+        let sign = if self.nume < 0 && p % 2 != 0 { -1 } else { 1 };
+        let value = nume.checked_pow(p.unsigned_abs())?;
+        i32::try_from(sign * i128::try_from(value).ok()?).ok()
+    }
+
+    /// 减法，结果无法精确表示时返回 `None`
+    ///
+    /// `-` 在精确的差超出 `i32` 范围时会 shrink 近似，因此两个十分接近的数相减可能得到 `ZERO`。
+    /// 本方法在这种情况下返回 `None`，`Some(ZERO)` 总是意味着两者确实相等
+    ///
+    /// # 返回值
+    /// `Option<Self>`，以下情况返回 `None`：
+    /// - 任一操作数为特殊值（`NAN` 或无穷）
+    /// - 精确的差需要 shrink 才能表示，或与 `INFINITY` 的内部表示冲突
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 2).checked_sub(Fraction::new(1, 3)), Some(Fraction::new(1, 6)));
+    ///
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert!(a - b == Fraction::ZERO);
+    /// assert_eq!(a.checked_sub(b), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {}, 
+            _ => return None
+        }
+
+        let (sign, nume, deno) = self.exact_sub(rhs);
+        if nume > Self::LIMITER as u64 || deno > Self::LIMITER as u64 {
+            return None;
+        }
+        let (nume, deno) = (nume as i32 * sign, deno as i32);
+        match Self::determine_frac_type(nume, deno) {
+            frac_type @ (Type::Normal | Type::Zero) => Some(Self { nume, deno, frac_type }), 
+            _ => None
+        }
+    }
+
+    /// 加法，同时返回结果是否经过近似
+    ///
+    /// 和与 `self + rhs` 相同。精确的和的分子或分母超出 `i32` 范围而需要 shrink 时
+    /// （包括正数溢出为 `INFINITY`、负数溢出为 `MIN`），第二个返回值为 `true`。
+    /// 可用于在长链计算中记录精度损失的位置
+    ///
+    /// # 返回值
+    /// `(Self, bool)`，特殊值按 `+` 的规则计算，结果总是精确的，第二个返回值为 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 2).add_reporting(Fraction::new(1, 3)), (Fraction::new(5, 6), false));
+    ///
+    /// let (sum, approximated) = Fraction::new(1, 65536).add_reporting(Fraction::new(1, 65535));
+    /// assert!(approximated);
+    /// assert_eq!(sum, Fraction::new(1, 65536) + Fraction::new(1, 65535));
+    /// ```
+    pub fn add_reporting(self, rhs: Self) -> (Self, bool) {
+        let sum = self + rhs;
+        let approximated = match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (sign, nume, deno) = self.exact_add(rhs);
+                let limit = Self::LIMITER as u64;
+                // i32::MAX/1 即为 INFINITY，同样不能精确表示
+                nume > limit || deno > limit || (sign > 0 && nume == limit && deno == 1)
+            }
+            _ => false
+        };
+        (sum, approximated)
+    }
+
+    /// 返回 shrink 实际进行近似的次数（仅在启用 `stats` feature 时可用）
+    ///
+    /// 结果已在范围内、无需近似的 shrink 不计入。计数器为全局共享，用于定位长计算中精度损失发生的位置
+    ///
+    /// # 示例
+    /// ```
+    /// # #[cfg(feature = "stats")] {
+    /// # use fraction::Fraction;
+    /// 
+    /// let before = Fraction::shrink_approximation_count();
+    /// let _ = Fraction::new(1, 65537) * Fraction::new(1, 65539);
+    /// assert!(Fraction::shrink_approximation_count() > before);
+    /// # }
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn shrink_approximation_count() -> u64 {
+        SHRINK_APPROXIMATIONS.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// 将 shrink 近似次数清零（仅在启用 `stats` feature 时可用）
+    #[cfg(feature = "stats")]
+    pub fn reset_shrink_stats() {
+        SHRINK_APPROXIMATIONS.store(0, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// 按指定的精度策略进行加法
+    ///
+    /// 精确计算和之后只 shrink 一次，分母不超过 `policy.max_denom()`（分子仍以 `i32` 为限）。
+    /// 较小的分母上限以精度换取后续运算的速度；默认策略与 `+` 的结果相同
+    ///
+    /// # 参数
+    /// - `rhs`: 加数
+    /// - `policy`: 精度策略
+    ///
+    /// # 返回值
+    /// `Self`，特殊值按 `+` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, PrecisionPolicy};
+    /// 
+    /// let policy = PrecisionPolicy::new(100);
+    /// let sum = Fraction::new(1, 97).add_with(Fraction::new(1, 89), policy);
+    /// assert!(sum.denominator() <= 100);
+    /// assert_eq!(Fraction::new(1, 2).add_with(Fraction::new(1, 3), PrecisionPolicy::default()), Fraction::new(5, 6));
+    /// ```
+    pub fn add_with(self, rhs: Self, policy: PrecisionPolicy) -> Self {
+        match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (sign, nume, deno) = self.exact_add(rhs);
+                Self::from_unsigned_bounded(sign, nume as u128, deno as u128, policy.max_denom() as u128)
+            }
+            _ => self + rhs
+        }
+    }
+
+    /// 按指定的精度策略进行减法
+    ///
+    /// 与 [`add_with`](Self::add_with) 相同，特殊值按 `-` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, PrecisionPolicy};
+    /// 
+    /// let diff = Fraction::new(1, 97).sub_with(Fraction::new(1, 89), PrecisionPolicy::new(1000));
+    /// assert!(diff.denominator() <= 1000);
+    /// ```
+    pub fn sub_with(self, rhs: Self, policy: PrecisionPolicy) -> Self {
+        match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (sign, nume, deno) = self.exact_sub(rhs);
+                Self::from_unsigned_bounded(sign, nume as u128, deno as u128, policy.max_denom() as u128)
+            }
+            _ => self - rhs
+        }
+    }
+
+    /// 按指定的精度策略进行乘法
+    ///
+    /// 与 [`add_with`](Self::add_with) 相同，特殊值按 `*` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, PrecisionPolicy};
+    /// 
+    /// let product = Fraction::new(22, 7).mul_with(Fraction::new(113, 355), PrecisionPolicy::new(10));
+    /// assert_eq!(product, Fraction::from(1));
+    /// ```
+    pub fn mul_with(self, rhs: Self, policy: PrecisionPolicy) -> Self {
+        match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let sign = self.i32_sign() * rhs.i32_sign();
+                let nume = self.nume.unsigned_abs() as u128 * rhs.nume.unsigned_abs() as u128;
+                let deno = self.deno as u128 * rhs.deno as u128;
+                Self::from_unsigned_bounded(sign, nume, deno, policy.max_denom() as u128)
+            }
+            _ => self * rhs
+        }
+    }
+
+    /// 按指定的精度策略进行除法
+    ///
+    /// 与 [`add_with`](Self::add_with) 相同，除数为零或特殊值时按 `/` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, PrecisionPolicy};
+    /// 
+    /// let quotient = Fraction::from(1).div_with(Fraction::new(113, 355), PrecisionPolicy::new(10));
+    /// assert_eq!(quotient, Fraction::new(22, 7));
+    /// ```
+    pub fn div_with(self, rhs: Self, policy: PrecisionPolicy) -> Self {
+        match (self.frac_type, rhs.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal) => {
+                let sign = self.i32_sign() * rhs.i32_sign();
+                let nume = self.nume.unsigned_abs() as u128 * rhs.deno as u128;
+                let deno = self.deno as u128 * rhs.nume.unsigned_abs() as u128;
+                Self::from_unsigned_bounded(sign, nume, deno, policy.max_denom() as u128)
+            }
+            _ => self / rhs
+        }
+    }
+
+    /// 加法，以 `Ok`/`Err` 区分结果是否精确
+    ///
+    /// 精确的和可以表示时返回 `Ok`，需要 shrink 近似时返回 `Err`，其中仍包含与 `self + rhs` 相同的近似值，
+    /// 调用者可以按需处理或直接使用。判断规则与 [`add_reporting`](Self::add_reporting) 相同
+    ///
+    /// # 返回值
+    /// `Result<Self, Self>`，特殊值按 `+` 的规则计算，返回 `Ok`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 2).add_exact(Fraction::new(1, 3)), Ok(Fraction::new(5, 6)));
+    ///
+    /// let a = Fraction::new(1, 65536);
+    /// let b = Fraction::new(1, 65535);
+    /// assert_eq!(a.add_exact(b), Err(a + b));
+    /// // 近似值仍然可用
+    /// let sum = a.add_exact(b).unwrap_or_else(|approx| approx);
+    /// assert!(sum > a);
+    /// ```
+    pub fn add_exact(self, rhs: Self) -> Result<Self, Self> {
+        match self.add_reporting(rhs) {
+            (sum, false) => Ok(sum), 
+            (approx, true) => Err(approx)
+        }
+    }
+
+    /// 乘以 `10^exp`，负数 `exp` 表示除以 `10^-exp`
+    ///
+    /// 中间结果以 `u128` 精确计算，仅在最后 shrink 一次。
+    /// 结果超出范围时与 `*`、`/` 的结果相同：正数过大变为 `INFINITY`，负数过大变为 `MIN`，绝对值过小变为 `ZERO`
+    ///
+    /// # 参数
+    /// - `exp`: 10 的指数
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 4).scale_pow10(2), Fraction::from(25));
+    /// assert_eq!(Fraction::new(1, 4).scale_pow10(-1), Fraction::new(1, 40));
+    /// assert!(Fraction::new(1, 4).scale_pow10(100).is_infinity());
+    /// assert_eq!(Fraction::new(-1, 4).scale_pow10(100), Fraction::MIN);
+    /// assert!(Fraction::new(1, 4).scale_pow10(-100).is_zero());
+    /// ```
+    pub fn scale_pow10(self, exp: i32) -> Self {
+        if self.frac_type != Type::Normal || exp == 0 {
+            return self;
+        }
+
+        // 10^28 < 2^95，更大的指数必然溢出或下溢
+        const MAX_EXP: u32 = 28;
+        let sign = self.i32_sign();
+        let k = exp.unsigned_abs();
+        if k > MAX_EXP {
+            return if exp > 0 { Self::from_unsigned(sign, Self::LIMITER, 1) } else { Self::ZERO };
+        }
+
+        let scale = 10u128.pow(k);
+        let (nume, deno) = (self.nume.unsigned_abs() as u128, self.deno as u128);
+        let (nume, deno) = if exp > 0 { (nume * scale, deno) } else { (nume, deno * scale) };
+        let gcd = Self::gcd(nume, deno);
+        let (nume, deno) = (nume / gcd, deno / gcd);
+
+        // 另一侧不超过 2^31，因此超过 2^95 的一侧决定了结果溢出或下溢
+        const BOUND: u128 = 1 << 95;
+        if nume >= BOUND {
+            Self::from_unsigned(sign, Self::LIMITER, 1)
+        } else if deno >= BOUND {
+            Self::ZERO
+        } else {
+            Self::from_unsigned(sign, nume, deno)
+        }
+    }
+
+    /// 转换为十进制定点整数，即 `self * 10^scale` 舍入后的整数
+    ///
+    /// 常用于以整数存储金额（如以分为单位）。精确计算后按四舍六入五成双（银行家舍入）取整
+    ///
+    /// # 参数
+    /// - `scale`: 小数位数
+    ///
+    /// # 返回值
+    /// `Option<i64>`，非有限值或结果超出 `i64` 范围时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 4).to_scaled_int(2), Some(25));
+    /// assert_eq!(Fraction::new(-2, 3).to_scaled_int(2), Some(-67));
+    /// assert_eq!(Fraction::new(1, 8).to_scaled_int(2), Some(12));
+    /// assert_eq!(Fraction::NAN.to_scaled_int(2), None);
+    /// ```
+    pub fn to_scaled_int(self, scale: u32) -> Option<i64> {
+        match self.frac_type {
+            Type::Zero => return Some(0), 
+            Type::Normal => {}, 
+            _ => return None
+        }
+
+        // |self| >= 2^-31，scale 超过 28 时结果必然超出 i64 范围
+        const MAX_SCALE: u32 = 28;
+        if scale > MAX_SCALE {
+            return None;
+        }
+        let nume = self.nume as i128 * 10i128.pow(scale);
+        i64::try_from(Self::round_half_even(nume, self.deno as i128)).ok()
+    }
+
+    /// 由十进制定点整数创建分数，即 `v / 10^scale`
+    ///
+    /// # 参数
+    /// - `v`: 定点整数
+    /// - `scale`: 小数位数
+    ///
+    /// # 返回值
+    /// `Self`，化简后超出范围时与运算结果相同，使用 shrink 近似
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_scaled_int(25, 2), Fraction::new(1, 4));
+    /// assert_eq!(Fraction::from_scaled_int(-1250, 3), Fraction::new(-5, 4));
+    /// assert_eq!(Fraction::from_scaled_int(7, 0), Fraction::from(7));
+    /// ```
+    pub fn from_scaled_int(v: i64, scale: u32) -> Self {
+        // |v| < 2^63，scale 超过 28 时结果小于 MIN_POSITIVE，shrink 后为零
+        const MAX_SCALE: u32 = 28;
+        if scale > MAX_SCALE {
+            return Self::ZERO;
+        }
+        Self::from_i128(v as i128, 10i128.pow(scale))
+    }
+
+    /// 拆分为向下取整的整数部分与小数部分
+    ///
+    /// 返回 `(floor, self - floor)`，小数部分总在 `[0, 1)` 内。
+    /// 与带分数形式（向零取整）不同，负数同样向下取整，这正是连分数展开的每一步所需
+    ///
+    /// # 返回值
+    /// `(i32, Self)`，无穷与 `NAN` 返回 `(0, NAN)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 3).split_floor(), (2, Fraction::new(1, 3)));
+    /// assert_eq!(Fraction::new(-7, 3).split_floor(), (-3, Fraction::new(2, 3)));
+    /// assert_eq!(Fraction::from(-4).split_floor(), (-4, Fraction::ZERO));
+    /// ```
+    pub fn split_floor(&self) -> (i32, Self) {
+        match self.frac_type {
+            Type::Zero => (0, Self::ZERO), 
+            // 余数与分母互质，因此小数部分已是最简形式
+            Type::Normal => (
+                self.nume.div_euclid(self.deno), 
+                Self::new(self.nume.rem_euclid(self.deno), self.deno)
+            ), 
+            _ => (0, Self::NAN)
+        }
+    }
+
+    /// 将值折回 `[0, 1)`，即 `self - floor(self)`
+    ///
+    /// 与 [`split_floor`](Self::split_floor) 的小数部分相同，负数同样得到非负结果。
+    /// 适用于振荡器相位的累加：每一步先加相位增量再折回，数值保持有界。
+    /// 折回本身是精确的，但增量的分母与相位的分母之积超过 `i32` 时加法会 shrink，误差会逐步累积
+    ///
+    /// # 返回值
+    /// `Self`，无穷与 `NAN` 返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(-1, 3).wrap_unit(), Fraction::new(2, 3));
+    /// assert_eq!(Fraction::new(7, 4).wrap_unit(), Fraction::new(3, 4));
+    ///
+    /// let step = Fraction::new(3, 8);
+    /// let mut phase = Fraction::ZERO;
+    /// for _ in 0..8 {
+    ///     phase = (phase + step).wrap_unit();
+    /// }
+    /// assert_eq!(phase, Fraction::ZERO);
+    /// ```
+    pub fn wrap_unit(self) -> Self {
+        self.split_floor().1
+    }
+
+    /// 将以秒为单位的值拆分为时、分、秒与不足一秒的部分
+    ///
+    /// 先按 [`split_floor`](Self::split_floor) 向下取整，再以 3600 与 60 为进制拆分整数秒。
+    /// 负数同样向下取整，只有小时为负，其余部分总是非负，如 `-1/2` 秒为 `(-1, 59, 59, 1/2)`，
+    /// 即 `-1` 时加上 `59` 分 `59.5` 秒
+    ///
+    /// # 返回值
+    /// `(i64, u8, u8, Self)`，依次为时、分 (`0..=59`)、秒 (`0..=59`) 与 `[0, 1)` 内的剩余部分；
+    /// 无穷与 `NAN` 返回 `(0, 0, 0, NAN)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7265, 2).to_hms(), (1, 0, 32, Fraction::new(1, 2)));
+    /// assert_eq!(Fraction::from(120).to_hms(), (0, 2, 0, Fraction::ZERO));
+    /// assert_eq!(Fraction::new(-1, 2).to_hms(), (-1, 59, 59, Fraction::new(1, 2)));
+    /// ```
+    pub fn to_hms(self) -> (i64, u8, u8, Self) {
+        let (seconds, sub_second) = self.split_floor();
+        if sub_second.is_nan() {
+            return (0, 0, 0, Self::NAN);
+        }
+
+        let seconds = seconds as i64;
+        let minutes = seconds.rem_euclid(3600) / 60;
+        (seconds.div_euclid(3600), minutes as u8, seconds.rem_euclid(60) as u8, sub_second)
+    }
+
+    /// 判断是否恰好等于整数 `n`
+    ///
+    /// 即最简形式的分母为 `1` 且分子为 `n`，无需先构造 `Fraction::from(n)`
+    ///
+    /// # 返回值
+    /// `bool`，无穷与 `NAN` 为 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(10, 2).equals_int(5));
+    /// assert!(Fraction::ZERO.equals_int(0));
+    /// assert!(!Fraction::new(1, 2).equals_int(0));
+    /// assert!(!Fraction::INFINITY.equals_int(i32::MAX));
+    /// ```
+    pub fn equals_int(&self, n: i32) -> bool {
+        matches!(self.frac_type, Type::Normal | Type::Zero) && self.deno == 1 && self.nume == n
+    }
+
+    /// 判断是否为二进分数，即最简形式的分母为 2 的幂
+    ///
+    /// # 返回值
+    /// `bool`，整数（包括零）为 `true`，无穷与 `NAN` 为 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(3, 8).is_dyadic());
+    /// assert!(Fraction::from(5).is_dyadic());
+    /// assert!(!Fraction::new(1, 3).is_dyadic());
+    /// assert!(!Fraction::INFINITY.is_dyadic());
+    /// ```
+    pub fn is_dyadic(&self) -> bool {
+        matches!(self.frac_type, Type::Normal | Type::Zero) && (self.deno as u32).is_power_of_two()
+    }
+
+    /// 判断是否为有限小数，即最简形式的分母只含质因数 2 与 5
+    ///
+    /// # 返回值
+    /// `bool`，整数（包括零）为 `true`，无穷与 `NAN` 为 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(3, 8).is_terminating_decimal());
+    /// assert!(Fraction::new(7, 20).is_terminating_decimal());
+    /// assert!(!Fraction::new(1, 3).is_terminating_decimal());
+    /// ```
+    pub fn is_terminating_decimal(&self) -> bool {
+        self.terminating_length().is_some()
+    }
+
+    /// 有限小数所需的小数位数
+    ///
+    /// 即分母中 2 与 5 的指数的较大者，如 `3/8 = 0.375` 为 `3`，`7/20 = 0.35` 为 `2`
+    ///
+    /// # 返回值
+    /// `Option<usize>`，整数（包括零）为 `Some(0)`，不是有限小数（包括无穷与 `NAN`）时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(3, 8).terminating_length(), Some(3));
+    /// assert_eq!(Fraction::new(7, 20).terminating_length(), Some(2));
+    /// assert_eq!(Fraction::new(1, 3).terminating_length(), None);
+    /// ```
+    pub fn terminating_length(&self) -> Option<usize> {
+        if !matches!(self.frac_type, Type::Normal | Type::Zero) {
+            return None;
+        }
+
+        let mut deno = self.deno as u32;
+        let twos = deno.trailing_zeros();
+        deno >>= twos;
+        let mut fives = 0;
+        while deno.is_multiple_of(5) {
+            deno /= 5;
+            fives += 1;
+        }
+        (deno == 1).then_some(twos.max(fives) as usize)
+    }
+
+    /// 判断是否为 `other` 的整数倍，即 `self / other` 精确为整数
+    ///
+    /// 以 `i64` 精确计算 `(a * d) % (b * c)`，不受 `/` 的 shrink 影响
+    ///
+    /// # 返回值
+    /// `bool`，`ZERO` 是任意非零有限值的倍数；`other` 为 `ZERO`、任一方为无穷或 `NAN` 时返回 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(3, 2).is_multiple_of(&Fraction::new(1, 2)));
+    /// assert!(Fraction::new(-3, 4).is_multiple_of(&Fraction::new(1, 4)));
+    /// assert!(!Fraction::new(2, 3).is_multiple_of(&Fraction::new(1, 2)));
+    /// assert!(!Fraction::from(1).is_multiple_of(&Fraction::ZERO));
+    /// ```
+    pub fn is_multiple_of(&self, other: &Self) -> bool {
+        match (self.frac_type, other.frac_type) {
+            (Type::Zero, Type::Normal) => true, 
+            (Type::Normal, Type::Normal) => {
+                let (a, b) = (self.nume as i64, self.deno as i64);
+                let (c, d) = (other.nume as i64, other.deno as i64);
+                (a * d) % (b * c) == 0
+            }
+            _ => false
+        }
+    }
+
+    /// 最接近的、分母为 `2^k` (`k <= max_bits`) 的分数
+    ///
+    /// 舍入到最近的 `n / 2^k`，恰在中间时取 `n` 为偶数者，结果化简后分母可能更小。
+    /// `k` 取不超过 `max_bits` 且使 `n` 不超出 `i32` 范围的最大值，因此绝对值较大时精度会降低
+    ///
+    /// # 参数
+    /// - `max_bits`: 分母的最大位数，超过 30 时按 30 处理（`2^31` 超出 `i32` 范围）
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).nearest_dyadic(4), Fraction::new(5, 16));
+    /// assert_eq!(Fraction::new(3, 8).nearest_dyadic(4), Fraction::new(3, 8));
+    /// assert_eq!(Fraction::new(2, 3).nearest_dyadic(0), Fraction::from(1));
+    /// ```
+    pub fn nearest_dyadic(self, max_bits: u32) -> Self {
+        if self.frac_type != Type::Normal {
+            return self;
+        }
+        // k = 0 时 n 为最接近的整数，总在范围内
+        (0..=max_bits.min(30)).rev()
+            .map(|k| (Self::round_half_even((self.nume as i128) << k, self.deno as i128), 1i128 << k))
+            .find(|(nume, _)| nume.unsigned_abs() <= Self::MAX.nume as u128)
+            .map_or(self, |(nume, deno)| Self::from_i128(nume, deno))
+    }
+
+    /// 原地取反，保持特殊值语义
+    ///
+    /// 与 `*x = -*x` 结果相同，但不重新构造分数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let mut a = Fraction::new(2, 3);
+    /// a.negate();
+    /// assert_eq!(a, Fraction::new(-2, 3));
+    ///
+    /// let mut inf = Fraction::INFINITY;
+    /// inf.negate();
+    /// assert!(inf.is_neg_infinity());
+    /// ```
+    pub fn negate(&mut self) {
+        match self.frac_type {
+            Type::Infinity => *self = Self::NEG_INFINITY, 
+            Type::NegInfinity => *self = Self::INFINITY, 
+            Type::NaN | Type::Zero => {}, 
+            Type::Normal => *self = -*self
+        }
+    }
+
+    /// 全序比较，与 `f64::total_cmp` 类似
+    ///
+    /// 顺序为 `NEG_INFINITY` < 有限值 < `INFINITY` < `NAN`，`NAN` 与自身相等。
+    /// 可用于排序等需要全序的场合
+    ///
+    /// # 返回值
+    /// `Ordering`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::cmp::Ordering;
+    /// 
+    /// let a = Fraction::new(1, 2);
+    /// assert_eq!(a.total_cmp(&Fraction::new(2, 3)), Ordering::Less);
+    /// assert_eq!(Fraction::NAN.total_cmp(&Fraction::INFINITY), Ordering::Greater);
+    /// assert_eq!(Fraction::NAN.total_cmp(&Fraction::NAN), Ordering::Equal);
+    /// ```
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self.frac_type, other.frac_type) {
+            (Type::NaN, Type::NaN) => Ordering::Equal, 
+            (Type::NaN, _) => Ordering::Greater, 
+            (_, Type::NaN) => Ordering::Less, 
+            _ => self.partial_cmp(other).unwrap()
+        }
+    }
+
+    /// 比较大小，将无法比较（含 `NAN`）作为单独的结果
+    ///
+    /// 与 `partial_cmp` 相同，但返回 [`Comparison`] 而不是 `Option<Ordering>`，
+    /// 在 `match` 中可以直接处理 `NAN` 的情况
+    ///
+    /// # 返回值
+    /// `Comparison`，任一方为 `NAN` 时为 `Unordered`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, Comparison};
+    /// 
+    /// let describe = |x: Fraction| match x.compare(&Fraction::ZERO) {
+    ///     Comparison::Less => "negative", 
+    ///     Comparison::Equal => "zero", 
+    ///     Comparison::Greater => "positive", 
+    ///     Comparison::Unordered => "nan", 
+    /// };
+    /// assert_eq!(describe(Fraction::new(-1, 2)), "negative");
+    /// assert_eq!(describe(Fraction::NAN), "nan");
+    /// ```
+    pub fn compare(&self, other: &Self) -> Comparison {
+        match self.partial_cmp(other) {
+            Some(Ordering::Less) => Comparison::Less, 
+            Some(Ordering::Equal) => Comparison::Equal, 
+            Some(Ordering::Greater) => Comparison::Greater, 
+            None => Comparison::Unordered
+        }
+    }
+
+    /// 精确比较两个已存储的分数
+    ///
+    /// 使用 `i128` 交叉相乘比较两者的最简形式，结果总是精确的，特殊值的处理与 `partial_cmp` 相同。
+    ///
+    /// 注意区分"比较两个已存储的值"与"比较运算结果"：运算结果在溢出时会被 shrink 近似，
+    /// 因此 `(a - b) == Fraction::ZERO` 可能在 `a != b` 时成立，应直接比较 `a` 与 `b`
+    ///
+    /// # 返回值
+    /// `Option<Ordering>`，存在 NaN 时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::cmp::Ordering;
+    /// 
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert!(a - b == Fraction::ZERO);
+    /// assert_eq!(a.cmp_exact(&b), Some(Ordering::Greater));
+    ///
+    /// assert_eq!(Fraction::NAN.cmp_exact(&a), None);
+    /// ```
+    pub fn cmp_exact(&self, other: &Self) -> Option<Ordering> {
+        match (self.frac_type, other.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (a, b) = (self.nume as i128, self.deno as i128);
+                let (c, d) = (other.nume as i128, other.deno as i128);
+                Some((a * d).cmp(&(c * b)))
+            }, 
+            _ => self.partial_cmp(other)
+        }
+    }
+
+    /// 比较两数绝对值的大小
+    ///
+    /// 以 `i128` 直接比较 `|a|*d` 与 `|c|*b`，不构造绝对值，因此不受 `MIN` 取绝对值变为 `INFINITY` 的影响
+    ///
+    /// # 返回值
+    /// `Option<Ordering>`，存在 `NAN` 时返回 `None`；两种无穷的绝对值相等且大于任何有限值
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::cmp::Ordering;
+    /// 
+    /// assert_eq!(Fraction::new(-3, 4).abs_cmp(&Fraction::new(1, 2)), Some(Ordering::Greater));
+    /// assert_eq!(Fraction::new(-1, 2).abs_cmp(&Fraction::new(1, 2)), Some(Ordering::Equal));
+    /// assert_eq!(Fraction::NEG_INFINITY.abs_cmp(&Fraction::INFINITY), Some(Ordering::Equal));
+    /// assert_eq!(Fraction::NAN.abs_cmp(&Fraction::ZERO), None);
+    /// ```
+    pub fn abs_cmp(&self, other: &Self) -> Option<Ordering> {
+        let is_infinite = |x: &Self| matches!(x.frac_type, Type::Infinity | Type::NegInfinity);
+        match (self.frac_type, other.frac_type) {
+            (Type::NaN, _) | (_, Type::NaN) => None, 
+            _ if is_infinite(self) || is_infinite(other) => Some(is_infinite(self).cmp(&is_infinite(other))), 
+            _ => {
+                let (a, b) = (self.nume.unsigned_abs() as u64, self.deno as u64);
+                let (c, d) = (other.nume.unsigned_abs() as u64, other.deno as u64);
+                Some((a * d).cmp(&(c * b)))
+            }
+        }
+    }
+
+    /// 两个不同的值之间可能的最小间隔，即 [`MIN_POSITIVE`](Self::MIN_POSITIVE)
+    ///
+    /// 可作为收敛循环的步长阈值：`(curr - prev).abs() <= Fraction::epsilon()`
+    /// 比与 `ZERO` 比较更明确地表达了"已达到精度上限"
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::epsilon(), Fraction::MIN_POSITIVE);
+    /// assert!(Fraction::epsilon() > Fraction::ZERO);
+    /// ```
+    pub const fn epsilon() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    /// 判断两数存储的值是否完全相同
+    ///
+    /// 比较约分后的分子、分母与类型。与 `==` 的区别仅在于 NaN 与自身相同。
+    /// 不要用 `a - b == Fraction::ZERO` 判断相等：减法结果会被 shrink，
+    /// 两个不同的分数的差可能舍入为零；需要"在本库精度下相等"时请使用 [`same_shrunk`](Self::same_shrunk)
+    ///
+    /// # 返回值
+    /// `bool`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert!(a - b == Fraction::ZERO);
+    /// assert!(!a.eq_exact(&b));
+    /// assert!(a.same_shrunk(b));
+    ///
+    /// assert!(Fraction::new(2, 4).eq_exact(&Fraction::new(1, 2)));
+    /// assert!(Fraction::NAN.eq_exact(&Fraction::NAN));
+    /// ```
+    pub fn eq_exact(&self, other: &Self) -> bool {
+        (self.nume, self.deno, self.frac_type) == (other.nume, other.deno, other.frac_type)
+    }
+
+    /// 判断两数在本库的舍入精度下是否相等
+    ///
+    /// 当两者精确的差 shrink 后为零时返回 `true`，这正是 `a - b == Fraction::ZERO` 的判断依据。
+    /// 两个写法不同、但在 `i32` 精度下无法区分的分数，通常希望被视为相等，此时应使用本方法。
+    /// 需要注意该关系不具有传递性
+    ///
+    /// # 返回值
+    /// `bool`，特殊值仅在两者相同（NaN 除外）时为 `true`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert!(a != b);
+    /// assert!(a.same_shrunk(b));
+    ///
+    /// assert!(!Fraction::new(1, 3).same_shrunk(Fraction::new(1, 2)));
+    /// assert!(Fraction::INFINITY.same_shrunk(Fraction::INFINITY));
+    /// assert!(!Fraction::NAN.same_shrunk(Fraction::NAN));
+    /// ```
+    pub fn same_shrunk(self, other: Self) -> bool {
+        match (self.frac_type, other.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (a, b) = (self.nume as i128, self.deno as i128);
+                let (c, d) = (other.nume as i128, other.deno as i128);
+                Self::from_i128(a * d - c * b, b * d).is_zero()
+            }, 
+            _ => self == other
+        }
+    }
+
+    /// 两数之间的距离 `|self - other|`，以 `f64` 表示
+    ///
+    /// 由 `i128` 精确计算的差 `|a*d - c*b| / (b*d)` 直接转换，不会先 shrink 差值，
+    /// 因此即使两数非常接近也能得到准确的非零距离
+    ///
+    /// # 返回值
+    /// `f64`，与 `f64` 的减法规则相同：含 `NAN` 或两者为同号无穷时为 `NAN`，其余含无穷时为 `INFINITY`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert_eq!(f64::from(a - b), 0.0);
+    /// assert!(a.distance(&b) > 0.0);
+    /// assert_eq!(Fraction::new(1, 4).distance(&Fraction::new(-1, 4)), 0.5);
+    /// ```
+    pub fn distance(&self, other: &Self) -> f64 {
+        match (self.frac_type, other.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (a, b) = (self.nume as i128, self.deno as i128);
+                let (c, d) = (other.nume as i128, other.deno as i128);
+                (a * d - c * b).unsigned_abs() as f64 / (b * d) as f64
+            }, 
+            _ => (f64::from(*self) - f64::from(*other)).abs()
+        }
+    }
+
+    /// 不构造和，精确判断 `a + b` 的符号
+    ///
+    /// 以 `i128` 交叉相乘直接比较 `a` 与 `-b`，不会受到 shrink 的影响。
+    /// 这是判断 `a == -b`、`a > -b` 的安全写法，也适用于迭代收敛的判断：
+    /// `Fraction::cmp_sum_zero(curr, -prev) == Some(Ordering::Equal)` 不会把两个不同的值误判为相等
+    ///
+    /// # 参数
+    /// - `a`, `b`: 两个加数
+    ///
+    /// # 返回值
+    /// `Option<Ordering>`，`a + b` 与零的大小关系。
+    /// 与 `partial_cmp` 相同，和为 `NAN`（任一参数为 `NAN`，或两者为符号相反的无穷大）时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::cmp::Ordering;
+    /// 
+    /// let a = Fraction::new(155937625, 24970004);
+    /// let b = Fraction::new(2103597937, 336845254);
+    /// assert!(a - b == Fraction::ZERO);
+    /// assert_eq!(Fraction::cmp_sum_zero(a, -b), Some(Ordering::Greater));
+    /// assert_eq!(Fraction::cmp_sum_zero(Fraction::new(1, 3), Fraction::new(-2, 6)), Some(Ordering::Equal));
+    /// assert_eq!(Fraction::cmp_sum_zero(Fraction::NEG_INFINITY, Fraction::MAX), Some(Ordering::Less));
+    /// assert_eq!(Fraction::cmp_sum_zero(Fraction::INFINITY, Fraction::NEG_INFINITY), None);
+    /// ```
+    pub fn cmp_sum_zero(a: Self, b: Self) -> Option<Ordering> {
+        match (a.frac_type, b.frac_type) {
+            (Type::NaN, _) | (_, Type::NaN) => None,
+            (Type::Infinity, Type::NegInfinity) | (Type::NegInfinity, Type::Infinity) => None,
+            (Type::Infinity, _) | (_, Type::Infinity) => Some(Ordering::Greater),
+            (Type::NegInfinity, _) | (_, Type::NegInfinity) => Some(Ordering::Less),
+            _ => {
+                let (p, q) = (a.nume as i128, a.deno as i128);
+                let (r, s) = (b.nume as i128, b.deno as i128);
+                Some((p * s + r * q).cmp(&0))
+            }
+        }
+    }
+
+    /// 计算两数的中点 `(self + other) / 2`
+    ///
+    /// 中间结果以 `i128` 精确计算，仅在最后 shrink 一次
+    ///
+    /// # 返回值
+    /// `Self`，特殊值按先加后除的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 2).midpoint(Fraction::new(1, 3)), Fraction::new(5, 12));
+    /// assert_eq!(Fraction::MAX.midpoint(Fraction::MAX), Fraction::MAX);
+    /// assert!(Fraction::INFINITY.midpoint(Fraction::NEG_INFINITY).is_nan());
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        match (self.frac_type, other.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (a, b) = (self.nume as i128, self.deno as i128);
+                let (c, d) = (other.nume as i128, other.deno as i128);
+                Self::from_i128(a * d + c * b, 2 * b * d)
+            }, 
+            _ => (self + other) / 2
+        }
+    }
+
+    /// 求平方根的巴比伦迭代（牛顿法）的一步 `(n / guess + guess) / 2`
+    ///
+    /// 中间结果以 `i128` 精确计算，仅在最后 shrink 一次。作为公开的构件，调用方可以自行决定停止条件，
+    /// 例如两次迭代的差不超过 [`epsilon`](Self::epsilon) 时停止，而不是要求差恰好为 `ZERO`：
+    /// shrink 的误差可能使迭代在两个相邻的值之间来回振荡，此时差永远不为零
+    ///
+    /// # 参数
+    /// - `n`: 被开方数
+    /// - `guess`: 当前的近似值
+    ///
+    /// # 返回值
+    /// `Self`：
+    /// - `n` 为负数或 `NAN` 时返回 `NAN`，`n` 为 `ZERO` 时返回 `ZERO`
+    /// - 其余特殊值按 `(n / guess).midpoint(guess)` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let n = Fraction::from(2);
+    /// assert_eq!(Fraction::babylonian_step(n, Fraction::ONE), Fraction::new(3, 2));
+    /// assert_eq!(Fraction::babylonian_step(n, Fraction::new(3, 2)), Fraction::new(17, 12));
+    ///
+    /// let mut prev = Fraction::ONE;
+    /// let mut curr = Fraction::babylonian_step(n, prev);
+    /// while (curr - prev).abs() > Fraction::epsilon() {
+    ///     prev = curr;
+    ///     curr = Fraction::babylonian_step(n, prev);
+    /// }
+    /// assert!((curr.to_f64() - 2f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn babylonian_step(n: Self, guess: Self) -> Self {
+        if n.is_nan() || n.is_negative() {
+            return Self::NAN;
+        } else if n.is_zero() {
+            return Self::ZERO;
+        }
+
+        match (n.frac_type, guess.frac_type) {
+            (Type::Normal, Type::Normal) => {
+                let (a, b) = (n.nume as i128, n.deno as i128);
+                let (c, d) = (guess.nume as i128, guess.deno as i128);
+                // (a/b) / (c/d) + c/d = (a*d*d + c*c*b) / (b*c*d)
+                let (nume, deno) = (a * d * d + c * c * b, 2 * b * c * d);
+                Self::from_i128(nume * deno.signum(), deno.abs())
+            }, 
+            _ => (n / guess).midpoint(guess)
+        }
+    }
+
+    /// 线性插值 `self + (other - self) * t`
+    ///
+    /// `t` 通常位于 `[0, 1]` 内，但不做限制。中间结果以 `i128` 精确计算，仅在最后 shrink 一次，
+    /// 因此对于有限值，`t` 为 `0` 时精确返回 `self`，为 `1` 时精确返回 `other`
+    ///
+    /// # 返回值
+    /// `Self`，特殊值按 `self + (other - self) * t` 的规则处理
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let a = Fraction::new(1, 3);
+    /// let b = Fraction::from(2);
+    /// assert_eq!(a.lerp(b, Fraction::ZERO), a);
+    /// assert_eq!(a.lerp(b, Fraction::from(1)), b);
+    /// assert_eq!(a.lerp(b, Fraction::new(1, 2)), a.midpoint(b));
+    /// assert_eq!(a.lerp(b, Fraction::from(2)), Fraction::new(11, 3));
+    ///
+    /// assert!(a.lerp(Fraction::INFINITY, Fraction::new(1, 2)).is_infinity());
+    /// ```
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        match (self.frac_type, other.frac_type, t.frac_type) {
+            (Type::Normal | Type::Zero, Type::Normal | Type::Zero, Type::Normal | Type::Zero) => {
+                let (a, b) = (self.nume as i128, self.deno as i128);
+                let (c, d) = (other.nume as i128, other.deno as i128);
+                let (e, f) = (t.nume as i128, t.deno as i128);
+                Self::from_i128(a * d * f + (c * b - a * d) * e, b * d * f)
+            }, 
+            _ => self + (other - self) * t
+        }
+    }
+
+    /// 列出 `[lo, hi]` 内所有分母不超过 `max_denom` 的最简分数，按升序排列且不重复
+    ///
+    /// 相当于把 Farey 序列推广到任意区间。对每个分母逐一枚举分子，
+    /// 时间与结果长度约为 `O(max_denom * max_denom * (hi - lo))`，区间或分母较大时请注意开销
+    ///
+    /// # 参数
+    /// - `lo`: 区间下界（包含）
+    /// - `hi`: 区间上界（包含）
+    /// - `max_denom`: 分母上限 (正数)
+    ///
+    /// # Panics
+    /// 当 `max_denom` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Vec<Self>`，`lo > hi` 或任一边界不是有限值时为空
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = Fraction::enumerate_range(Fraction::ZERO, Fraction::from(1), 3);
+    /// assert_eq!(values, [
+    ///     Fraction::ZERO, Fraction::new(1, 3), Fraction::new(1, 2), 
+    ///     Fraction::new(2, 3), Fraction::from(1), 
+    /// ]);
+    /// ```
+    pub fn enumerate_range(lo: Self, hi: Self, max_denom: i32) -> Vec<Self> {
+        assert!(max_denom > 0, "max_denom must be positive");
+        let finite = |x: &Self| matches!(x.frac_type, Type::Normal | Type::Zero);
+        if !finite(&lo) || !finite(&hi) || lo > hi {
+            return Vec::new();
+        }
+
+        let (a, b) = (lo.nume as i64, lo.deno as i64);
+        let (c, d) = (hi.nume as i64, hi.deno as i64);
+        let limit = Self::LIMITER as i64;
+        let mut values = Vec::new();
+        for q in 1..=max_denom as i64 {
+            // ceil(lo * q) ..= floor(hi * q)，且分子不超出范围
+            let first = (-(-a * q).div_euclid(b)).max(-limit);
+            let last = (c * q).div_euclid(d).min(limit);
+            values.extend(
+                (first..=last)
+                    .filter(|&p| Self::gcd(p.abs(), q) == 1 && !(p == limit && q == 1))
+                    .map(|p| Self::new(p as i32, q as i32))
+            );
+        }
+        values.sort_by(Self::total_cmp);
+        values
+    }
+
+    /// 按 Calkin-Wilf 序列依次生成所有正有理数
+    ///
+    /// 顺序为 `1, 1/2, 2, 1/3, 3/2, 2/3, 3, 1/4, ...`，每个正有理数恰好出现一次且均为最简形式。
+    /// 使用 Newman 的递推式 `x → 1 / (2⌊x⌋ - x + 1)`，每一项的计算量为常数，无需去重。
+    /// 下一项的分子或分母超出 `i32` 范围时迭代结束
+    ///
+    /// # 返回值
+    /// `impl Iterator<Item = Self>`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let terms: Vec<Fraction> = Fraction::calkin_wilf().take(5).collect();
+    /// assert_eq!(terms, [
+    ///     Fraction::from(1), Fraction::new(1, 2), Fraction::from(2), Fraction::new(1, 3), Fraction::new(3, 2), 
+    /// ]);
+    /// ```
+    pub fn calkin_wilf() -> impl Iterator<Item = Self> {
+        let mut next = Some((1i64, 1i64));
+        std::iter::from_fn(move || {
+            let (a, b) = next?;
+            // a/b → b / ((2⌊a/b⌋ + 1) * b - a)
+            let deno = (2 * (a / b) + 1) * b - a;
+            next = (deno <= Self::LIMITER as i64).then_some((b, deno));
+
+            let (nume, deno) = (a as i32, b as i32);
+            match Self::determine_frac_type(nume, deno) {
+                Type::Normal => Some(Self { nume, deno, frac_type: Type::Normal }), 
+                // i32::MAX/1 即为 INFINITY
+                _ => None
+            }
+        })
+    }
+
+    /// 按全序 ([`total_cmp`](Self::total_cmp)) 排序，`NAN` 排在最后
+    ///
+    /// # 返回值
+    /// `Vec<Self>`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::NAN, Fraction::new(1, 2), Fraction::NEG_INFINITY, Fraction::new(1, 3)];
+    /// let sorted = Fraction::sorted(&values);
+    /// assert!(sorted[0].is_neg_infinity());
+    /// assert_eq!(sorted[1..3], [Fraction::new(1, 3), Fraction::new(1, 2)]);
+    /// assert!(sorted[3].is_nan());
+    /// ```
+    pub fn sorted(values: &[Self]) -> Vec<Self> {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(Self::total_cmp);
+        sorted
+    }
+
+    /// 中位数
+    ///
+    /// 长度为奇数时返回中间的元素，为偶数时返回中间两个元素的精确[中点](Self::midpoint)。
+    /// 排序使用全序，`NAN` 被视为最大值参与计算
+    ///
+    /// # 返回值
+    /// `Self`，空切片返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let odd = [Fraction::from(3), Fraction::new(1, 2), Fraction::from(1)];
+    /// assert_eq!(Fraction::median(&odd), Fraction::from(1));
+    /// let even = [Fraction::from(3), Fraction::new(1, 2), Fraction::from(1), Fraction::from(2)];
+    /// assert_eq!(Fraction::median(&even), Fraction::new(3, 2));
+    /// assert!(Fraction::median(&[]).is_nan());
+    /// ```
+    pub fn median(values: &[Self]) -> Self {
+        let sorted = Self::sorted(values);
+        let len = sorted.len();
+        if len == 0 {
+            Self::NAN
+        } else if len % 2 == 1 {
+            sorted[len / 2]
+        } else {
+            sorted[len / 2 - 1].midpoint(sorted[len / 2])
+        }
+    }
+
+    /// 二项式系数 `C(n, k)`
+    ///
+    /// 按 `C(n, i) = C(n, i - 1) * (n - i + 1) / i` 逐项精确计算，每一步都能整除
+    ///
+    /// # 参数
+    /// - `n`: 总数
+    /// - `k`: 选取的个数
+    ///
+    /// # 返回值
+    /// `Self`，分母为 `1`：
+    /// - `k > n` 时返回 `ZERO`
+    /// - 结果超出 `i32` 范围（与 `INFINITY` 的内部表示冲突也算在内）时返回 `INFINITY`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::binomial(5, 2), Fraction::from(10));
+    /// assert_eq!(Fraction::binomial(0, 0), Fraction::from(1));
+    /// assert!(Fraction::binomial(2, 3).is_zero());
+    /// assert!(Fraction::binomial(100, 50).is_infinity());
+    /// ```
+    pub fn binomial(n: u32, k: u32) -> Self {
+        if k > n {
+            return Self::ZERO;
+        }
+
+        let k = k.min(n - k) as u128;
+        let n = n as u128;
+        let mut result = 1u128;
+        // i <= n/2 时 C(n, i) 单调递增，一旦超出范围最终结果也必然超出
+        for i in 1..=k {
+            result = result * (n - i + 1) / i;
+            if result > Self::MAX.nume as u128 {
+                return Self::INFINITY;
+            }
+        }
+        Self::from_unsigned(1, result, 1)
+    }
+
+    /// 在候选值中寻找与 `self` 最接近的一个
+    ///
+    /// 距离 `|self - c|` 以 `i128` 精确比较，不受 shrink 影响。距离相同时取分母较小者，仍相同时取靠前者
+    ///
+    /// # 参数
+    /// - `candidates`: 候选值
+    ///
+    /// # 返回值
+    /// `Option<Self>`，只考虑有限的候选值（`NAN` 与无穷被跳过）。
+    /// `self` 不是有限值或没有有限的候选值时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let candidates = [Fraction::new(1, 4), Fraction::new(1, 3), Fraction::new(1, 2)];
+    /// assert_eq!(Fraction::new(26, 100).closest_of(&candidates), Some(Fraction::new(1, 4)));
+    /// assert_eq!(Fraction::new(3, 8).closest_of(&candidates), Some(Fraction::new(1, 3)));
+    /// assert_eq!(Fraction::new(3, 8).closest_of(&[]), None);
+    /// ```
+    pub fn closest_of(self, candidates: &[Self]) -> Option<Self> {
+        if !matches!(self.frac_type, Type::Normal | Type::Zero) {
+            return None;
+        }
+
+        let (a, b) = (self.nume as i128, self.deno as i128);
+        // 距离 |a/b - p/q| = |aq - pb| / bq，b 相同，故只需比较 |aq - pb| / q
+        let distance = |c: &Self| ((a * c.deno as i128 - c.nume as i128 * b).abs(), c.deno as i128);
+        candidates.iter()
+            .filter(|c| matches!(c.frac_type, Type::Normal | Type::Zero))
+            .map(|c| (c, distance(c)))
+            .min_by(|(c_1, (n_1, d_1)), (c_2, (n_2, d_2))| 
+                (n_1 * d_2).cmp(&(n_2 * d_1)).then(c_1.deno.cmp(&c_2.deno))
+            )
+            .map(|(c, _)| *c)
+    }
+
+    /// 依次生成前缀和（累计和）
+    ///
+    /// 第 `k` 项为前 `k + 1` 个元素之和，特殊值按 `+` 的规则传播
+    ///
+    /// # 参数
+    /// - `iter`: 分数迭代器
+    ///
+    /// # 返回值
+    /// `impl Iterator<Item = Self>`，长度与输入相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// let sums: Vec<Fraction> = Fraction::cumulative_sum(values.into_iter()).collect();
+    /// assert_eq!(sums, [Fraction::new(1, 2), Fraction::new(5, 6), Fraction::from(1)]);
+    /// ```
+    pub fn cumulative_sum<I: Iterator<Item = Self>>(iter: I) -> impl Iterator<Item = Self> {
+        iter.scan(Self::ZERO, |acc, x| {
+            *acc += x;
+            Some(*acc)
+        })
+    }
+
+    /// 按份额将整数 `total` 分配为若干整数，使其和恰为 `total`（最大余数法 / Hamilton 方法）
+    ///
+    /// 先按份额占总份额的比例计算每一份的配额并向下取整，
+    /// 再把剩余的单位依次分给小数部分最大的几份（相同时靠前者优先）。份额之和不必为 `1`
+    ///
+    /// 份额之和以 [`Accumulator`] 精确累加后 shrink 一次，每一份的配额再以 `i128` 与该和交叉相乘精确计算，
+    /// 不需要所有分母的公倍数。分母两两互质且很大时，和可能经过 shrink，此时配额相对于 shrink 后的和，
+    /// 各份配额之和可能略多于或略少于 `total`，多出的单位从小数部分最小的几份中扣除，结果之和仍恰为 `total`
+    ///
+    /// # 参数
+    /// - `shares`: 各份的份额
+    /// - `total`: 待分配的总数
+    ///
+    /// # 返回值
+    /// `Option<Vec<i32>>`，与 `shares` 一一对应，和为 `total`。以下情况返回 `None`：
+    /// - 份额为负数或不是有限值
+    /// - 份额之和为零（包括 `shares` 为空）或超出范围
+    /// - `total` 为负数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let shares = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// assert_eq!(Fraction::apportion(&shares, 10), Some(vec![5, 3, 2]));
+    /// assert_eq!(Fraction::apportion(&[Fraction::from(1); 3], 10), Some(vec![4, 3, 3]));
+    /// assert_eq!(Fraction::apportion(&[Fraction::from(1), Fraction::from(-1)], 10), None);
+    /// ```
+    pub fn apportion(shares: &[Self], total: i32) -> Option<Vec<i32>> {
+        if total < 0 || !shares.iter().all(|x| x.is_zero() || (x.is_normal() && x.is_positive())) {
+            return None;
+        }
+        let mut acc = Accumulator::new();
+        shares.iter().for_each(|&x| acc.push(x));
+        let sum = acc.finish();
+        if !sum.is_normal() {
+            return None;
+        }
+
+        // a/b * total / (c/d) = a*d*total / (b*c)，保存 (商, 余数, 除数)
+        let (c, d) = (sum.nume as i128, sum.deno as i128);
+        let quotas: Vec<(i128, i128, i128)> = shares.iter().map(|x| {
+            let (q, m) = (x.nume as i128 * d * total as i128, x.deno as i128 * c);
+            (q / m, q % m, m)
+        }).collect();
+        let mut result: Vec<i128> = quotas.iter().map(|&(floor, _, _)| floor).collect();
+
+        // 按余数 r/m 从大到小排序，r < m < 2^62，交叉相乘不会溢出
+        let mut order: Vec<usize> = (0..shares.len()).collect();
+        order.sort_by(|&i, &j| (quotas[j].1 * quotas[i].2).cmp(&(quotas[i].1 * quotas[j].2)));
+
+        let mut leftover = total as i128 - result.iter().sum::<i128>();
+        while leftover > 0 {
+            for &i in order.iter().take(leftover as usize) {
+                result[i] += 1;
+                leftover -= 1;
+            }
+        }
+        while leftover < 0 {
+            for &i in order.iter().rev() {
+                if leftover < 0 && result[i] > 0 {
+                    result[i] -= 1;
+                    leftover += 1;
+                }
+            }
+        }
+        // 每一份非负且和为 total，均在 i32 范围内
+        Some(result.into_iter().map(|n| n as i32).collect())
+    }
+
+    /// 减小 shrink 误差的求和
+    ///
+    /// 按绝对值从小到大（[`abs_cmp`](Self::abs_cmp)）排序后使用 [`Accumulator`] 以 `i128` 精确累加，
+    /// 最后只 shrink 一次。累加器的中间结果超出范围而不得不提前 shrink 时，
+    /// 先累加的小量已经合并，误差小于按原顺序逐项使用 `+` 累加。相当于分数版本的补偿求和
+    ///
+    /// # 返回值
+    /// `Self`，特殊值按 `+` 的规则处理，空切片返回 `ZERO`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values: Vec<_> = (1..=10).map(|n| Fraction::new(1, n)).collect();
+    /// assert_eq!(Fraction::kahan_sum(&values), Fraction::new(7381, 2520));
+    /// assert!(Fraction::kahan_sum(&[Fraction::ONE, Fraction::NAN]).is_nan());
+    /// ```
+    pub fn kahan_sum(values: &[Self]) -> Self {
+        // abs_cmp 只在存在 NAN 时返回 None，排除后即为全序
+        if values.iter().any(|x| x.is_nan()) {
+            return Self::NAN;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.abs_cmp(b).expect("NaN has been excluded"));
+
+        let mut sum = Accumulator::new();
+        sorted.into_iter().for_each(|x| sum.push(x));
+        sum.finish()
+    }
+
+    /// 调和平均数 `n / Σ(1/x_i)`
+    ///
+    /// 倒数按 [`reciprocal`](Self::reciprocal) 的规则计算，因此含有零时倒数和为 `INFINITY`，结果为 `ZERO`
+    /// （即 `x_i → 0` 时的极限）。倒数和使用 [`Accumulator`] 精确累加，`n` 与倒数和的商也精确计算，仅 shrink 一次
+    ///
+    /// 倒数和恰为零时（如 `[1, -1]`）调和平均数无定义，返回 `NAN`
+    ///
+    /// # 返回值
+    /// `Self`，空切片返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::from(1), Fraction::from(2), Fraction::from(4)];
+    /// assert_eq!(Fraction::harmonic_mean(&values), Fraction::new(12, 7));
+    /// assert!(Fraction::harmonic_mean(&[Fraction::from(2), Fraction::ZERO]).is_zero());
+    /// assert!(Fraction::harmonic_mean(&[Fraction::from(1), Fraction::from(-1)]).is_nan());
+    /// assert!(Fraction::harmonic_mean(&[]).is_nan());
+    /// ```
+    pub fn harmonic_mean(values: &[Self]) -> Self {
+        if values.is_empty() {
+            return Self::NAN;
+        }
+        let mut sum = Accumulator::new();
+        values.iter().for_each(|x| sum.push(x.reciprocal()));
+        if let Some(special) = sum.special {
+            return Self::ONE / special;
+        } else if sum.nume == 0 {
+            return Self::NAN;
+        }
+
+        // n / (nume/deno) = n·deno / nume，先约去 n 与 nume 的公因数
+        let n = values.len() as i128;
+        let gcd = Self::gcd(n, sum.nume.abs());
+        let (n, nume) = (n / gcd * sum.nume.signum(), sum.nume.abs() / gcd);
+        match n.checked_mul(sum.deno).filter(|x| x.abs() < Accumulator::BOUND) {
+            Some(product) => Self::from_i128(product, nume), 
+            None => Self::from_i128(n, 1) * Self::from_i128(sum.deno, nume), 
+        }
+    }
+
+    /// 几何平均数，即所有值乘积的 `n` 次方根
+    ///
+    /// 乘积以 `u128` 精确计算（过程中保持最简），仅当分子与分母均为完全 `n` 次方时结果为有理数
+    ///
+    /// # 返回值
+    /// `Option<Self>`，以下情况返回 `None`：
+    /// - 空切片
+    /// - 存在非正数或特殊值
+    /// - 乘积超出 `u128` 范围
+    /// - 方根为无理数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::from(1), Fraction::from(2), Fraction::from(4)];
+    /// assert_eq!(Fraction::geometric_mean(&values), Some(Fraction::from(2)));
+    /// assert_eq!(Fraction::geometric_mean(&[Fraction::new(1, 2), Fraction::new(9, 8)]), Some(Fraction::new(3, 4)));
+    /// assert_eq!(Fraction::geometric_mean(&[Fraction::from(1), Fraction::from(2)]), None);
+    /// assert_eq!(Fraction::geometric_mean(&[Fraction::from(-1), Fraction::from(-4)]), None);
+    /// ```
+    pub fn geometric_mean(values: &[Self]) -> Option<Self> {
+        if values.is_empty() || values.iter().any(|x| !x.is_normal() || x.is_negative()) {
+            return None;
+        }
+
+        let (mut p, mut q) = (1u128, 1u128);
+        for x in values {
+            let (a, b) = (x.nume as u128, x.deno as u128);
+            let (g_1, g_2) = (Self::gcd(p, b), Self::gcd(a, q));
+            p = (p / g_1).checked_mul(a / g_2)?;
+            q = (q / g_2).checked_mul(b / g_1)?;
+        }
+
+        let n = u32::try_from(values.len()).ok()?;
+        let (p, q) = (Self::nth_root(p, n)?, Self::nth_root(q, n)?);
+        Some(Self::from_unsigned(1, p, q))
+    }
+
+    /// 所有有限值中最大的分母
+    ///
+    /// 特殊值会被忽略，`ZERO` 的分母为 `1`
+    ///
+    /// # 返回值
+    /// `i32`，没有有限值时返回 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::new(1, 2), Fraction::new(2, 7), Fraction::INFINITY];
+    /// assert_eq!(Fraction::max_denominator(&values), 7);
+    /// assert_eq!(Fraction::max_denominator(&[]), 0);
+    /// ```
+    pub fn max_denominator(values: &[Self]) -> i32 {
+        values.iter()
+            .filter(|v| matches!(v.frac_type, Type::Normal | Type::Zero))
+            .map(|v| v.deno)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// 所有值的最小公分母，即各分母的最小公倍数
+    ///
+    /// # 返回值
+    /// `Option<i32>`，含有无穷或 `NAN`，或结果超出 `i32` 范围时返回 `None`；空切片返回 `Some(1)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// assert_eq!(Fraction::lcd(&values), Some(6));
+    /// assert_eq!(Fraction::lcd(&[Fraction::new(1, 2), Fraction::NAN]), None);
+    /// ```
+    pub fn lcd(values: &[Self]) -> Option<i32> {
+        values.iter().try_fold(1i32, |acc, v| {
+            if !matches!(v.frac_type, Type::Normal | Type::Zero) {
+                return None;
+            }
+            let (factor, _, _) = Self::lcm(acc, v.deno);
+            acc.checked_mul(factor)
+        })
+    }
+
+    /// 将所有值通分到最小公分母
+    ///
+    /// 返回最小公分母 `D`（见 [`lcd`](Self::lcd)）与各值以 `D` 为分母时的分子，
+    /// 适用于需要统一分母的表格等场合
+    ///
+    /// # 返回值
+    /// `Option<(i32, Vec<i32>)>`，含有无穷或 `NAN`，或 `D`、某个分子超出 `i32` 范围时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// assert_eq!(Fraction::to_common_denominator(&values), Some((6, vec![3, 2, 1])));
+    /// assert_eq!(Fraction::to_common_denominator(&[Fraction::new(-3, 4), Fraction::ZERO]), Some((4, vec![-3, 0])));
+    /// ```
+    pub fn to_common_denominator(values: &[Self]) -> Option<(i32, Vec<i32>)> {
+        let denom = Self::lcd(values)?;
+        let numes = values.iter()
+            .map(|v| v.nume.checked_mul(denom / v.deno))
+            .collect::<Option<Vec<_>>>()?;
+        Some((denom, numes))
+    }
+
+    /// 分子与分母的质因数分解
+    ///
+    /// 分别返回最简形式的分子（绝对值）与分母的质因数分解，每项为 `(质数, 指数)`，按质数升序排列，
+    /// 如 `12/35` 为 `([(2, 2), (3, 1)], [(5, 1), (7, 1)])`。符号不包含在结果中，`1` 的分解为空
+    ///
+    /// # 返回值
+    /// `Option<(Vec<(i32, i32)>, Vec<(i32, i32)>)>`，`ZERO`、无穷与 `NAN` 返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let (nume, deno) = Fraction::new(12, 35).factorize().unwrap();
+    /// assert_eq!(nume, [(2, 2), (3, 1)]);
+    /// assert_eq!(deno, [(5, 1), (7, 1)]);
+    ///
+    /// assert_eq!(Fraction::from(-7).factorize(), Some((vec![(7, 1)], vec![])));
+    /// assert_eq!(Fraction::ZERO.factorize(), None);
+    /// ```
+    pub fn factorize(&self) -> Option<(Factors, Factors)> {
+        if self.frac_type != Type::Normal {
+            return None;
+        }
+        Some((Self::prime_factors(self.nume.unsigned_abs()), Self::prime_factors(self.deno as u32)))
+    }
+
+    // 试除法分解质因数，n 不超过 2^31，试除到 sqrt(n) 即可
+    fn prime_factors(mut n: u32) -> Factors {
+        let mut factors = Vec::new();
+        let mut p = 2u32;
+        while (p as u64) * (p as u64) <= n as u64 {
+            let mut exp = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exp += 1;
+            }
+            if exp > 0 {
+                factors.push((p as i32, exp));
+            }
+            p += if p == 2 { 1 } else { 2 };
+        }
+        if n > 1 {
+            factors.push((n as i32, 1));
+        }
+        factors
+    }
+
+    /// 转换为带分数形式的字符串
+    ///
+    /// 整数部分与真分数部分以空格分隔，符号作用于整个值，
+    /// 无整数部分或无分数部分时省略对应部分，特殊值与 `Display` 相同
+    ///
+    /// # 返回值
+    /// `String`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 3).to_mixed_string(), "2 1/3");
+    /// assert_eq!(Fraction::new(-7, 3).to_mixed_string(), "-2 1/3");
+    /// assert_eq!(Fraction::new(1, 3).to_mixed_string(), "1/3");
+    /// assert_eq!(Fraction::from(4).to_mixed_string(), "4");
+    /// assert_eq!(Fraction::INFINITY.to_mixed_string(), "inf");
+    /// ```
+    pub fn to_mixed_string(self) -> String {
+        if self.frac_type != Type::Normal || self.deno == 1 || self.nume.abs() < self.deno {
+            return self.to_string();
+        }
+
+        let sign = if self.nume < 0 { "-" } else { "" };
+        let nume = self.nume.unsigned_abs();
+        let deno = self.deno as u32;
+        format!("{}{} {}/{}", sign, nume / deno, nume % deno, deno)
+    }
+
+    /// 拆分为带符号的整数部分与非负的真分数部分
+    ///
+    /// 返回 `(整数部分, 真分数分子, 分母)`，整数部分向零取整并带有符号，真分数部分总为非负，
+    /// 如 `-7/3` 为 `(-2, 1, 3)`。
+    /// 注意整数部分为零时符号会丢失：`-1/3` 与 `1/3` 均为 `(0, 1, 3)`，需要时可配合 [`is_negative`](Self::is_negative) 使用
+    ///
+    /// # 返回值
+    /// `(i32, u32, u32)`，特殊值返回其内部表示：`INFINITY` 为 `(i32::MAX, 0, 1)`，
+    /// `NEG_INFINITY` 为 `(i32::MIN, 0, 1)`，`NAN` 为 `(0, 0, 0)`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 3).components(), (2, 1, 3));
+    /// assert_eq!(Fraction::new(-7, 3).components(), (-2, 1, 3));
+    /// assert_eq!(Fraction::new(-1, 3).components(), (0, 1, 3));
+    /// assert_eq!(Fraction::from(-4).components(), (-4, 0, 1));
+    /// ```
+    pub fn components(&self) -> (i32, u32, u32) {
+        match self.frac_type {
+            Type::Normal => {
+                let deno = self.deno as u32;
+                (self.nume / self.deno, self.nume.unsigned_abs() % deno, deno)
+            }
+            _ => (self.nume, 0, self.deno as u32)
+        }
+    }
+
+    /// 以指定的分母显示分数，不化简
+    ///
+    /// 将分数换算为 `k/denom` 的形式，不能整除时分子四舍六入五成双。
+    /// 适合每行都需要相同分母的表格对齐显示，如 `1/2` 以 12 为分母显示为 `"6/12"`
+    ///
+    /// # 参数
+    /// - `denom`: 显示使用的分母 (正数)
+    ///
+    /// # Panics
+    /// 当 `denom` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `String`，特殊值与 `Display` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 2).display_over(12), "6/12");
+    /// assert_eq!(Fraction::new(-1, 3).display_over(12), "-4/12");
+    /// assert_eq!(Fraction::new(1, 5).display_over(12), "2/12"); // 2.4 舍入为 2
+    /// assert_eq!(Fraction::INFINITY.display_over(12), "inf");
+    /// ```
+    pub fn display_over(&self, denom: i32) -> String {
+        assert!(denom > 0, "denominator must be positive");
+        match self.frac_type {
+            Type::Normal | Type::Zero => {
+                let nume = Self::round_half_even(self.nume as i128 * denom as i128, self.deno as i128);
+                format!("{}/{}", nume, denom)
+            }
+            _ => self.to_string()
+        }
+    }
+
+    /// 转换为分子、分母带有千位分隔符的字符串
+    ///
+    /// 分子与分母的数字每三位插入一个分隔符，其余格式与 `Display` 相同，
+    /// 如 `1234567/8` 为 `"1,234,567/8"`
+    ///
+    /// # 参数
+    /// - `sep`: 千位分隔符
+    ///
+    /// # 返回值
+    /// `String`，特殊值与 `Display` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1234567, 8).to_grouped_string(','), "1,234,567/8");
+    /// assert_eq!(Fraction::new(-1000000, 1).to_grouped_string('_'), "-1_000_000");
+    /// assert_eq!(Fraction::new(1, 3).to_grouped_string(','), "1/3");
+    /// assert_eq!(Fraction::NAN.to_grouped_string(','), "nan");
+    /// ```
+    pub fn to_grouped_string(self, sep: char) -> String {
+        if self.frac_type != Type::Normal {
+            return self.to_string();
+        }
+
+        let group = |n: u32| {
+            let digits = n.to_string();
+            let mut grouped = String::with_capacity(digits.len() * 2);
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    grouped.push(sep);
+                }
+                grouped.push(c);
+            }
+            grouped
+        };
+        let sign = if self.nume < 0 { "-" } else { "" };
+        let nume = group(self.nume.unsigned_abs());
+        if self.deno == 1 {
+            format!("{}{}", sign, nume)
+        } else {
+            format!("{}{}/{}", sign, nume, group(self.deno as u32))
+        }
+    }
+
+    /// 转换为总是带有分母的 `"n/d"` 形式的字符串
+    ///
+    /// 与 `Display` 不同，整数与零也会输出分母，如 `5` 为 `"5/1"`，适合总是需要斜杠的场合
+    ///
+    /// # 返回值
+    /// `String`，特殊值与 `Display` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(5).to_ratio_string(), "5/1");
+    /// assert_eq!(Fraction::new(-2, 4).to_ratio_string(), "-1/2");
+    /// assert_eq!(Fraction::ZERO.to_ratio_string(), "0/1");
+    /// assert_eq!(Fraction::INFINITY.to_ratio_string(), "inf");
+    /// ```
+    pub fn to_ratio_string(self) -> String {
+        match self.frac_type {
+            Type::Normal | Type::Zero => format!("{}/{}", self.nume, self.deno), 
+            _ => self.to_string()
+        }
+    }
+
+    /// 从带分数形式的字符串解析分数
+    ///
+    /// 格式为 `"[符号]整数 分子/分母"`，符号作用于整个值，如 `"-2 1/3"` 为 `-7/3`。
+    /// 只有一部分时与 [`FromStr`] 相同，如 `"1/3"`、`"5"`
+    ///
+    /// # 返回值
+    /// `Result<Self, ParseFractionError>`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ParseFractionError};
+    /// 
+    /// assert_eq!(Fraction::from_mixed_string("2 1/3"), Ok(Fraction::new(7, 3)));
+    /// assert_eq!(Fraction::from_mixed_string("-2 1/3"), Ok(Fraction::new(-7, 3)));
+    /// assert_eq!(Fraction::from_mixed_string("-1/3"), Ok(Fraction::new(-1, 3)));
+    /// assert_eq!(Fraction::from_mixed_string("5"), Ok(Fraction::from(5)));
+    /// assert_eq!(Fraction::from_mixed_string("2 -1/3"), Err(ParseFractionError::InvalidFormat));
+    /// assert_eq!(Fraction::from_mixed_string(""), Err(ParseFractionError::Empty));
+    /// ```
+    pub fn from_mixed_string(s: &str) -> Result<Self, ParseFractionError> {
+        let s = s.trim();
+        let (whole, frac) = match s.split_once(char::is_whitespace) {
+            Some((whole, frac)) => (whole, frac.trim_start()),
+            None => return s.parse(),
+        };
+
+        let (sign, whole) = match whole.strip_prefix('-') {
+            Some(whole) => (-1, whole),
+            None => (1, whole.strip_prefix('+').unwrap_or(whole)),
+        };
+        let (nume, deno) = frac.split_once('/').ok_or(ParseFractionError::InvalidFormat)?;
+        let [whole, nume, deno] = [whole, nume, deno].map(|part| {
+            if part.starts_with(['+', '-']) {
+                Err(ParseFractionError::InvalidFormat)
+            } else {
+                part.parse::<u32>().map_err(|_| ParseFractionError::InvalidInteger)
+            }
+        });
+        let (whole, nume, deno) = (whole? as i128, nume? as i128, deno? as i128);
+        if deno == 0 {
+            return Err(ParseFractionError::InvalidFormat);
+        }
+
+        Ok(Self::from_i128(sign * (whole * deno + nume), deno))
+    }
+
+    /// 由给定进制下整数部分与小数部分的各位数字精确构造分数
+    ///
+    /// 值为 `int_digits` 表示的整数加上 `Σ d_i / radix^i`（`d_i` 为 `frac_digits` 的第 `i` 位，从 1 开始），
+    /// 全程精确计算，如十六进制的 `[1, 8]` 与 `[8]` 为 `24 + 8/16 = 49/2`。
+    /// 适用于已经切分好数字的流式解析器
+    ///
+    /// # 参数
+    /// - `int_digits`: 整数部分的各位数字，高位在前
+    /// - `frac_digits`: 小数部分的各位数字，高位在前
+    /// - `radix`: 进制，`2..=36`
+    ///
+    /// # Panics
+    /// 当 `radix` 不在 `2..=36` 内时触发 panic
+    ///
+    /// # 返回值
+    /// `Result<Self, ParseFractionError>`：
+    /// - 两部分都为空时返回 `Empty`
+    /// - 某位数字不小于 `radix`，最简形式超出 `i32` 范围，或位数过多使中间结果超出 `u128` 时返回 `InvalidInteger`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ParseFractionError};
+    /// 
+    /// assert_eq!(Fraction::from_digits(&[3], &[1, 4], 10), Ok(Fraction::new(157, 50)));
+    /// assert_eq!(Fraction::from_digits(&[1, 0], &[1], 2), Ok(Fraction::new(5, 2)));
+    /// assert_eq!(Fraction::from_digits(&[15, 15], &[], 16), Ok(Fraction::from(255)));
+    /// assert_eq!(Fraction::from_digits(&[2], &[], 2), Err(ParseFractionError::InvalidInteger));
+    /// ```
+    pub fn from_digits(int_digits: &[u8], frac_digits: &[u8], radix: u32) -> Result<Self, ParseFractionError> {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        if int_digits.is_empty() && frac_digits.is_empty() {
+            return Err(ParseFractionError::Empty);
+        }
+
+        let radix = radix as u128;
+        let (mut nume, mut deno) = (0u128, 1u128);
+        for (i, &d) in int_digits.iter().chain(frac_digits).enumerate() {
+            if d as u128 >= radix {
+                return Err(ParseFractionError::InvalidInteger);
+            }
+            nume = nume.checked_mul(radix)
+                .and_then(|n| n.checked_add(d as u128))
+                .ok_or(ParseFractionError::InvalidInteger)?;
+            if i >= int_digits.len() {
+                deno = deno.checked_mul(radix).ok_or(ParseFractionError::InvalidInteger)?;
+            }
+        }
+
+        let gcd_val = Self::gcd(nume, deno);
+        let (nume, deno) = (nume / gcd_val, deno / gcd_val);
+        if nume > Self::LIMITER || deno > Self::LIMITER {
+            return Err(ParseFractionError::InvalidInteger);
+        }
+        let value = Self::new(nume as i32, deno as i32);
+        match value.frac_type {
+            Type::Normal | Type::Zero => Ok(value), 
+            _ => Err(ParseFractionError::InvalidInteger)
+        }
+    }
+
+    /// 从 Unicode 分数字符解析分数
+    ///
+    /// 支持 `¼`、`½`、`¾` (U+00BC..U+00BE) 与 `⅐` 至 `⅞` (U+2150..U+215E)，忽略首尾空白。
+    /// 适用于从文档、剪贴板中提取的文本
+    ///
+    /// # 返回值
+    /// `Option<Self>`，不是单个受支持的分数字符时为 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_unicode("½"), Some(Fraction::new(1, 2)));
+    /// assert_eq!(Fraction::from_unicode(" ⅝ "), Some(Fraction::new(5, 8)));
+    /// assert_eq!(Fraction::from_unicode("1/2"), None);
+    /// ```
+    pub fn from_unicode(s: &str) -> Option<Self> {
+        let mut chars = s.trim().chars();
+        let (c, None) = (chars.next()?, chars.next()) else {
+            return None;
+        };
+        Self::VULGAR_FRACTIONS.iter()
+            .find(|&&(glyph, _, _)| glyph == c)
+            .map(|&(_, nume, deno)| Self::new(nume, deno))
+    }
+
+    /// 转换为 Unicode 分数字符
+    ///
+    /// 是 [`from_unicode`](Self::from_unicode) 的逆运算，适用于紧凑的界面标签。
+    /// 没有对应字符时返回 `None`，调用方可以改用 `Display`
+    ///
+    /// # 返回值
+    /// `Option<String>`，整数与特殊值为 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(2, 4).to_unicode().as_deref(), Some("½"));
+    /// let label = Fraction::new(2, 7).to_unicode().unwrap_or_else(|| Fraction::new(2, 7).to_string());
+    /// assert_eq!(label, "2/7");
+    /// ```
+    pub fn to_unicode(self) -> Option<String> {
+        Self::VULGAR_FRACTIONS.iter()
+            .find(|&&(_, nume, deno)| (nume, deno) == (self.nume, self.deno) && self.frac_type == Type::Normal)
+            .map(|&(glyph, _, _)| glyph.to_string())
+    }
+
+    /// 计算由分数、整数、`+ - * /` 与括号组成的简单算术表达式
+    ///
+    /// 遵循通常的优先级与左结合，`/` 即除法，因此 `3/4` 既可视为字面量也可视为 `3 ÷ 4`，两者结果相同。
+    /// 数值（包括 `inf`、`nan`）使用 `FromStr` 解析，运算使用现有的运算符，
+    /// 因此除以零按现有规则得到 `INFINITY`/`NAN`，而不是错误
+    ///
+    /// # 参数
+    /// - `expr`: 表达式
+    ///
+    /// # 返回值
+    /// `Result<Self, ParseFractionError>`：
+    /// - `Empty`: 表达式为空
+    /// - `InvalidFormat`: 括号不匹配、缺少操作数等语法错误
+    /// - `InvalidInteger`: 数值无法解析或超出 `i32` 范围
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ParseFractionError};
+    /// 
+    /// assert_eq!(Fraction::eval("1/2 + 1/3 * 3/4"), Ok(Fraction::new(3, 4)));
+    /// assert_eq!(Fraction::eval("(1/2 + 1/3) * 6"), Ok(Fraction::from(5)));
+    /// assert_eq!(Fraction::eval("-3/4 - -1/4"), Ok(Fraction::new(-1, 2)));
+    /// assert!(Fraction::eval("1 / 0").unwrap().is_infinity());
+    /// assert_eq!(Fraction::eval("(1 + 2"), Err(ParseFractionError::InvalidFormat));
+    /// ```
+    pub fn eval(expr: &str) -> Result<Self, ParseFractionError> {
+        let mut parser = ExprParser::new(expr);
+        if parser.tokens.is_empty() {
+            return Err(ParseFractionError::Empty);
+        }
+        let value = parser.expr()?;
+        match parser.next() {
+            None => Ok(value), 
+            Some(_) => Err(ParseFractionError::InvalidFormat)
+        }
+    }
+
+    /// 使用贪心算法 (Fibonacci–Sylvester) 分解为互不相同的单位分数之和
+    ///
+    /// 每一步取不超过剩余值的最大单位分数，如 `4/13 = 1/4 + 1/18 + 1/468`
+    ///
+    /// # 返回值
+    /// `Vec<Self>`，各项分子均为 `1`，分母递增。以下情况返回空 `Vec`：
+    /// - 不在 `(0, 1)` 内的值（包括特殊值）
+    /// - 某一项的分母超出 `i32` 范围（贪心算法的分母增长很快）
+    ///
+    /// 因此非空的结果之和总是精确等于 `self`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(
+    ///     Fraction::new(4, 13).to_egyptian(), 
+    ///     [Fraction::new(1, 4), Fraction::new(1, 18), Fraction::new(1, 468)]
+    /// );
+    /// assert_eq!(Fraction::new(1, 5).to_egyptian(), [Fraction::new(1, 5)]);
+    /// assert!(Fraction::new(5, 4).to_egyptian().is_empty());
+    /// assert!(Fraction::new(-1, 4).to_egyptian().is_empty());
+    /// ```
+    pub fn to_egyptian(self) -> Vec<Self> {
+        if !self.is_positive() || self.nume >= self.deno {
+            return Vec::new();
+        }
+
+        let (mut a, mut b) = (self.nume as i128, self.deno as i128);
+        let mut terms = Vec::new();
+        while a != 0 {
+            let d = (b + a - 1) / a;
+            if d > Self::LIMITER as i128 {
+                return Vec::new();
+            }
+            terms.push(Self { nume: 1, deno: d as i32, frac_type: Type::Normal });
+
+            (a, b) = (a * d - b, b * d);
+            let gcd = Self::gcd(a, b);
+            (a, b) = (a / gcd, b / gcd);
+        }
+        terms
+    }
+
+    /// 依次生成连分数的各个渐进分数 `p_k/q_k`
+    ///
+    /// 每个渐进分数都比前一个更接近 `self`，且是分母不超过自身分母的最佳近似之一，
+    /// 最后一项精确等于 `self`
+    ///
+    /// # 返回值
+    /// `impl Iterator<Item = Self>`，特殊值（零除外）不产生任何项
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let pi = Fraction::new(103993, 33102);
+    /// let convergents: Vec<Fraction> = pi.convergents().collect();
+    /// assert_eq!(convergents, [
+    ///     Fraction::from(3), Fraction::new(22, 7), Fraction::new(333, 106), 
+    ///     Fraction::new(355, 113), Fraction::new(103993, 33102), 
+    /// ]);
+    ///
+    /// // -7/3 = [-3; 1, 2]
+    /// let convergents: Vec<Fraction> = Fraction::new(-7, 3).convergents().collect();
+    /// assert_eq!(convergents, [Fraction::from(-3), Fraction::from(-2), Fraction::new(-7, 3)]);
+    /// assert_eq!(Fraction::NAN.convergents().count(), 0);
+    /// ```
+    pub fn convergents(&self) -> impl Iterator<Item = Self> {
+        let (mut p_0, mut q_0, mut p_1, mut q_1) = (0, 1, 1, 0);
+        self.cf_terms().map(move |a| {
+            (p_0, q_0, p_1, q_1) = (p_1, q_1, a * p_1 + p_0, a * q_1 + q_0);
+            Self::new(p_1 as i32, q_1 as i32)
+        })
+    }
+
+    /// 连分数展开的项数
+    ///
+    /// 展开与 [`convergents`](Self::convergents) 相同（末项大于 1，整数只有一项），
+    /// 项数越多说明分数越"复杂"，即越难用分母较小的分数近似
+    ///
+    /// # 返回值
+    /// `usize`，无穷与 `NAN` 返回 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(7, 3).cf_length(), 2); // [2; 3]
+    /// assert_eq!(Fraction::from(1).cf_length(), 1);
+    /// assert_eq!(Fraction::INFINITY.cf_length(), 0);
+    /// ```
+    pub fn cf_length(&self) -> usize {
+        self.cf_terms().count()
+    }
+
+    /// 连分数展开各项之和
+    ///
+    /// 展开与 [`convergents`](Self::convergents) 相同，负数的首项为负，如 `-7/3 = [-3; 1, 2]` 的和为 `0`。
+    /// 与 [`cf_length`](Self::cf_length) 一同可用于衡量分数的复杂程度
+    ///
+    /// # 返回值
+    /// `i64`，无穷与 `NAN` 返回 `0`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(355, 113).cf_sum(), 3 + 7 + 16);
+    /// assert_eq!(Fraction::new(-7, 3).cf_sum(), 0);
+    /// assert_eq!(Fraction::NAN.cf_sum(), 0);
+    /// ```
+    pub fn cf_sum(&self) -> i64 {
+        self.cf_terms().sum()
+    }
+
+    /// [`stern_brocot_path`](Self::stern_brocot_path) 返回的路径的最大长度
+    pub const STERN_BROCOT_PATH_LIMIT: usize = 1 << 20;
+
+    /// 在 Stern-Brocot 树中从根 `1/1` 到该值的路径
+    ///
+    /// `false` 表示向左（变小），`true` 表示向右（变大）。路径由连分数展开 `[a_0; a_1, ..., a_n]` 得到：
+    /// 依次为 `a_0` 次向右、`a_1` 次向左……，最后一段少走一次。
+    /// 路径长度为 [`cf_sum`](Self::cf_sum) 减一，对 `1/n` 这样的值会很长（`MIN_POSITIVE` 约为 `2^31`），
+    /// 超过 [`STERN_BROCOT_PATH_LIMIT`](Self::STERN_BROCOT_PATH_LIMIT) 时不分配路径，返回 `None`
+    ///
+    /// # 返回值
+    /// `Option<Vec<bool>>`，`1/1` 返回空路径，非正数、无穷、`NAN` 与路径过长时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(2, 3).stern_brocot_path(), Some(vec![false, true]));
+    /// assert_eq!(Fraction::new(5, 2).stern_brocot_path(), Some(vec![true, true, false]));
+    /// assert_eq!(Fraction::from(1).stern_brocot_path(), Some(vec![]));
+    /// assert_eq!(Fraction::new(-1, 2).stern_brocot_path(), None);
+    /// assert_eq!(Fraction::MIN_POSITIVE.stern_brocot_path(), None);
+    /// ```
+    pub fn stern_brocot_path(&self) -> Option<Vec<bool>> {
+        if self.frac_type != Type::Normal || self.nume < 0 {
+            return None;
+        }
+
+        if self.cf_sum() - 1 > Self::STERN_BROCOT_PATH_LIMIT as i64 {
+            return None;
+        }
+
+        let terms: Vec<i64> = self.cf_terms().collect();
+        let mut path = Vec::new();
+        for (i, &a) in terms.iter().enumerate() {
+            let count = if i + 1 == terms.len() { a - 1 } else { a };
+            path.extend(std::iter::repeat_n(i % 2 == 0, count as usize));
+        }
+        Some(path)
+    }
+
+    /// 从 Stern-Brocot 树的根 `1/1` 出发，沿路径到达的值
+    ///
+    /// 是 [`stern_brocot_path`](Self::stern_brocot_path) 的逆运算。维护左右两个边界，
+    /// 当前结点总是两者的中间分数 `(a + c) / (b + d)`，向左走时替换右边界，向右走时替换左边界。
+    /// 结点超出 `i32` 范围时与运算结果相同，会被 shrink 为最接近的分数
+    ///
+    /// # 参数
+    /// - `path`: 路径，`false` 表示向左，`true` 表示向右
+    ///
+    /// # 返回值
+    /// `Self`，空路径返回 `1/1`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_stern_brocot_path(&[false, true]), Fraction::new(2, 3));
+    /// assert_eq!(Fraction::from_stern_brocot_path(&[]), Fraction::from(1));
+    ///
+    /// let x = Fraction::new(355, 113);
+    /// assert_eq!(Fraction::from_stern_brocot_path(&x.stern_brocot_path().unwrap()), x);
+    /// ```
+    pub fn from_stern_brocot_path(path: &[bool]) -> Self {
+        let ((mut a, mut b), (mut c, mut d)) = ((0u128, 1u128), (1u128, 0u128));
+        for &right in path {
+            let node = (a + c, b + d);
+            if right {
+                (a, b) = node;
+            } else {
+                (c, d) = node;
+            }
+            // 只有左右交替时结点才会指数增长，此时剩余路径带来的变化远小于 shrink 的误差
+            if a.max(c) > u64::MAX as u128 || b.max(d) > u64::MAX as u128 {
+                break;
+            }
+        }
+        Self::from_unsigned(1, a + c, b + d)
+    }
+
+    /// 由连分数的各项 `[a_0; a_1, a_2, ...]` 构造分数
+    ///
+    /// 结果超出 `i32` 范围时与运算结果相同，会被 shrink 为最接近的分数
+    ///
+    /// # 参数
+    /// - `terms`: 连分数的各项
+    ///
+    /// # 返回值
+    /// `Self`，`terms` 为空时返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_continued_fraction(&[3, 7, 15, 1]), Fraction::new(355, 113));
+    /// assert_eq!(Fraction::from_continued_fraction(&[-3, 1, 2]), Fraction::new(-7, 3));
+    /// assert!(Fraction::from_continued_fraction(&[]).is_nan());
+    /// ```
+    pub fn from_continued_fraction(terms: &[i32]) -> Self {
+        if terms.is_empty() {
+            return Self::NAN;
+        }
+        Self::from_cf_terms(terms.iter().map(|&a| a as i128))
+    }
+
+    /// 由循环连分数 `[initial; period, period, ...]` 构造二次无理数的有理近似
+    ///
+    /// 循环部分展开 `expansions` 次后取其渐进分数，展开次数越多越精确，
+    /// 直到受限于 `i32` 的精度
+    ///
+    /// # 参数
+    /// - `initial`: 不循环的前几项
+    /// - `period`: 循环节
+    /// - `expansions`: 循环节展开的次数
+    ///
+    /// # 返回值
+    /// `Self`，与 `from_continued_fraction` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// // √2 = [1; 2, 2, 2, ...]
+    /// assert_eq!(Fraction::from_periodic_cf(&[1], &[2], 3), Fraction::new(17, 12));
+    /// let sqrt2 = Fraction::from_periodic_cf(&[1], &[2], 30);
+    /// assert!((f64::from(sqrt2) - 2f64.sqrt()).abs() < 1e-15);
+    /// ```
+    pub fn from_periodic_cf(initial: &[i32], period: &[i32], expansions: u32) -> Self {
+        if initial.is_empty() && (period.is_empty() || expansions == 0) {
+            return Self::NAN;
+        }
+        let repeated = std::iter::repeat_n(period, expansions as usize).flatten();
+        Self::from_cf_terms(initial.iter().chain(repeated).map(|&a| a as i128))
+    }
+
+    /// 将分数近似为分母不超过 `cap` 的最接近的分数
+    ///
+    /// 与运算结果溢出时的 shrink 相同，只是分母上限由 `i32::MAX` 改为 `cap`。
+    /// 在每次运算后调用可以使分母保持较小，以精度换取速度
+    ///
+    /// # 参数
+    /// - `cap`: 分母上限 (正数)
+    ///
+    /// # Panics
+    /// 当 `cap` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let pi = Fraction::new(355, 113);
+    /// assert_eq!(pi.with_denominator_cap(10), Fraction::new(22, 7));
+    /// assert_eq!(pi.with_denominator_cap(1000), pi);
+    ///
+    /// assert!(Fraction::INFINITY.with_denominator_cap(10).is_infinity());
+    /// ```
+    pub fn with_denominator_cap(self, cap: i32) -> Self {
+        assert!(cap > 0, "denominator cap must be positive");
+        if self.frac_type != Type::Normal || self.deno <= cap {
+            return self;
+        }
+
+        let (nume, deno) = Self::shrink_bounded(
+            self.nume.unsigned_abs() as u128, self.deno as u128, Self::LIMITER, cap as u128
+        );
+        let (nume, deno) = (nume as i32 * self.i32_sign(), deno as i32);
+
+        Self {
+            nume, 
+            deno, 
+            frac_type: Self::determine_frac_type(nume, deno)
+        }
+    }
+
+    /// 近似为分子、分母分别能以给定位数存储的最接近的分数
+    ///
+    /// 分子按有符号整数计，绝对值不超过 `2^(num_bits-1) - 1`；分母按无符号整数计，不超过 `2^den_bits - 1`，
+    /// 两者同时不超过 `i32` 的范围。与 [`with_denominator_cap`](Self::with_denominator_cap) 不同，分子同样受限，
+    /// 在满足两个上限的分数中取最接近者（距离相等时取分母较小者），绝对值过大时取分子上限本身
+    ///
+    /// # 参数
+    /// - `num_bits`: 分子的位数 (正数，含符号位)
+    /// - `den_bits`: 分母的位数 (正数)
+    ///
+    /// # Panics
+    /// 当 `num_bits` 或 `den_bits` 为零时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变；`num_bits` 为 `1` 时只能表示零
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let pi = Fraction::new(355, 113);
+    /// assert_eq!(pi.fit_bits(8, 8), Fraction::new(22, 7));
+    /// assert_eq!(pi.fit_bits(10, 8), pi);
+    /// assert_eq!(Fraction::from(-300).fit_bits(8, 4), Fraction::from(-127));
+    /// ```
+    pub fn fit_bits(self, num_bits: u32, den_bits: u32) -> Self {
+        assert!(num_bits > 0 && den_bits > 0, "bit widths must be positive");
+        if self.frac_type != Type::Normal {
+            return self;
+        }
+
+        let nume_limit = ((1u128 << (num_bits.min(32) - 1)) - 1).min(Self::LIMITER);
+        let deno_limit = ((1u128 << den_bits.min(31)) - 1).min(Self::LIMITER);
+        if nume_limit == 0 {
+            return Self::ZERO;
+        }
+
+        let (nume, deno) = (self.nume.unsigned_abs() as u128, self.deno as u128);
+        let candidates = Self::bounding_candidates(nume, deno, nume_limit, deno_limit);
+        let (nume, deno) = Self::closest_candidate(nume, deno, candidates);
+        Self::from_unsigned(self.i32_sign(), nume, deno)
+    }
+
+    /// 将分母缩小到 `u16` 范围内，用于写入分母字段较窄的格式
+    ///
+    /// 与 [`with_denominator_cap`](Self::with_denominator_cap) 相同，分母已不超过 `max_denom` 时保持不变，
+    /// 否则近似为分母不超过 `max_denom` 的最接近的分数，**值可能改变**。
+    /// 结果的分母总能无损地存入 `u16` 字段，再读回时得到相同的分数
+    ///
+    /// # 参数
+    /// - `max_denom`: 分母上限 (正数)
+    ///
+    /// # Panics
+    /// 当 `max_denom` 为零时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let pi = Fraction::new(355, 113).fit_denominator(100);
+    /// assert_eq!(pi, Fraction::new(311, 99));
+    /// assert!(u16::try_from(Fraction::new(1, 3).fit_denominator(u16::MAX).denominator()).is_ok());
+    /// ```
+    pub fn fit_denominator(self, max_denom: u16) -> Self {
+        self.with_denominator_cap(max_denom as i32)
+    }
+
+    /// 向下舍入到 `k/denom` 的网格上，即不大于 `self` 的最大的 `k/denom`
+    ///
+    /// 负数同样向负无穷方向舍入，如 `-1/3` 以 4 为分母为 `-1/2`。结果化简后分母可能更小。
+    /// 与 [`round_up_to_denom`](Self::round_up_to_denom) 一同可得到 `self` 的保守下界与上界
+    ///
+    /// 网格上的值化简后超出 `i32` 范围时（仅当 `|self|` 与 `denom` 都很大时），取 `i32` 范围内
+    /// 不大于该值的最大的分数，此时结果不在网格上，但仍保证 `round_down_to_denom(x) <= x`
+    ///
+    /// # 参数
+    /// - `denom`: 网格的分母 (正数)
+    ///
+    /// # Panics
+    /// 当 `denom` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).round_down_to_denom(4), Fraction::new(1, 4));
+    /// assert_eq!(Fraction::new(-1, 3).round_down_to_denom(4), Fraction::new(-1, 2));
+    /// assert_eq!(Fraction::new(3, 4).round_down_to_denom(4), Fraction::new(3, 4));
+    /// ```
+    pub fn round_down_to_denom(self, denom: i32) -> Self {
+        self.round_to_denom(denom, false)
+    }
+
+    /// 向上舍入到 `k/denom` 的网格上，即不小于 `self` 的最小的 `k/denom`
+    ///
+    /// 规则与 [`round_down_to_denom`](Self::round_down_to_denom) 相同，方向相反，
+    /// 网格上的值超出 `i32` 范围时同样取不小于该值的最小的分数，保证 `x <= round_up_to_denom(x)`
+    ///
+    /// # 参数
+    /// - `denom`: 网格的分母 (正数)
+    ///
+    /// # Panics
+    /// 当 `denom` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).round_up_to_denom(4), Fraction::new(1, 2));
+    /// assert_eq!(Fraction::new(-1, 3).round_up_to_denom(4), Fraction::new(-1, 4));
+    /// ```
+    pub fn round_up_to_denom(self, denom: i32) -> Self {
+        self.round_to_denom(denom, true)
+    }
+
+    /// 四舍六入五成双到 `places` 位小数，即分母为 `10^places` 的网格上最接近的值
+    ///
+    /// 适用于金额等需要整洁小数的场合，结果仍以最简形式存储，如 `0.50` 为 `1/2`
+    ///
+    /// # 参数
+    /// - `places`: 小数位数，不超过 `9`（`10^places` 须在 `i32` 范围内）
+    ///
+    /// # Panics
+    /// 当 `places` 大于 `9` 时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，特殊值保持不变
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).to_decimal_places(2), Fraction::new(33, 100));
+    /// assert_eq!(Fraction::new(-2, 3).to_decimal_places(2), Fraction::new(-67, 100));
+    /// assert_eq!(Fraction::new(1, 8).to_decimal_places(2), Fraction::new(3, 25)); // 0.125 -> 0.12
+    /// ```
+    pub fn to_decimal_places(self, places: u32) -> Self {
+        assert!(places <= 9, "10^places must fit in i32");
+        if !matches!(self.frac_type, Type::Normal) {
+            return self;
+        }
+
+        let scale = 10i128.pow(places);
+        let nume = Self::round_half_even(self.nume as i128 * scale, self.deno as i128);
+        Self::from_i128(nume, scale)
+    }
+
+    /// 由基点创建分数，`1` 个基点为 `1/10000`
+    ///
+    /// # 参数
+    /// - `bps`: 基点数
+    ///
+    /// # 返回值
+    /// `Self`，即最简形式的 `bps/10000`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from_basis_points(2500), Fraction::new(1, 4));
+    /// assert_eq!(Fraction::from_basis_points(-15), Fraction::new(-3, 2000));
+    /// ```
+    pub fn from_basis_points(bps: i32) -> Self {
+        Self::new(bps, 10000)
+    }
+
+    /// 转换为最接近的整数基点，四舍六入五成双
+    ///
+    /// 是 [`from_basis_points`](Self::from_basis_points) 的逆运算，避免经由浮点数转换带来的误差
+    ///
+    /// # 返回值
+    /// `Option<i32>`，无穷、`NAN` 或结果超出 `i32` 范围时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::new(1, 3).to_basis_points(), Some(3333));
+    /// assert_eq!(Fraction::new(-1, 8).to_basis_points(), Some(-1250));
+    /// assert_eq!(Fraction::MAX.to_basis_points(), None);
+    /// ```
+    pub fn to_basis_points(self) -> Option<i32> {
+        match self.frac_type {
+            Type::Normal | Type::Zero => {
+                let bps = Self::round_half_even(self.nume as i128 * 10000, self.deno as i128);
+                i32::try_from(bps).ok()
+            }
+            _ => None
+        }
+    }
+
+    fn round_to_denom(self, denom: i32, ceil: bool) -> Self {
+        assert!(denom > 0, "denominator must be positive");
+        if !matches!(self.frac_type, Type::Normal) {
+            return self;
+        }
+
+        let (nume, deno) = (self.nume as i128 * denom as i128, self.deno as i128);
+        let floor = nume.div_euclid(deno);
+        let k = if ceil && nume.rem_euclid(deno) != 0 { floor + 1 } else { floor };
+        if k == 0 {
+            return Self::ZERO;
+        }
+
+        // k/denom 化简后仍超出范围时，从两侧夹住它的两个分数中取方向正确的一个，而不是最接近的一个。
+        // self 在 [MIN, MAX] 内，MIN 与 MAX 都在网格上，因此结果总是有限值
+        let (sign, k, denom) = (k.signum() as i32, k.unsigned_abs(), denom as u128);
+        let gcd_val = Self::gcd(k, denom);
+        let [a, b] = Self::bounding_candidates(k / gcd_val, denom / gcd_val, Self::LIMITER, Self::LIMITER);
+        let (lower, upper) = if a.0 * b.1 <= b.0 * a.1 { (a, b) } else { (b, a) };
+        // 对绝对值而言，正数向上舍入与负数向下舍入都是远离 0
+        let (nume, deno) = if ceil == (sign > 0) { upper } else { lower };
+        Self::from_unsigned(sign, nume, deno)
+    }
+
+    /// 浮点数在分母不超过 `max_denom` 的分数中的最佳有理近似
+    ///
+    /// 以 `x` 的精确二进制值为目标，沿 Stern-Brocot 树（连分数的渐进分数与中间分数）搜索
+    /// 从两侧夹住 `x` 的两个分数，所有分母不超过 `max_denom` 的分数都不会比其中较近者更接近 `x`。
+    /// 两者距离相等时取分母较小者，因此结果是距离最近的分数中分母最小的一个。
+    /// 与 [`with_denominator_cap`](Self::with_denominator_cap) 不同，不会先将 `x` shrink 到 `i32` 范围，
+    /// 避免了两次近似带来的误差。分子同样受 `i32` 范围限制，`|x|` 超出范围时按 shrink 的规则处理
+    ///
+    /// # 参数
+    /// - `x`: 需要近似的浮点数
+    /// - `max_denom`: 分母上限 (正数)
+    ///
+    /// # Panics
+    /// 当 `max_denom` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Self`，`NaN` 与无穷分别返回 `NAN` 与对应的无穷
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::best_rational(0.3333333, 3), Fraction::new(1, 3));
+    /// assert_eq!(Fraction::best_rational(std::f64::consts::PI, 10), Fraction::new(22, 7));
+    /// assert_eq!(Fraction::best_rational(std::f64::consts::PI, 110), Fraction::new(333, 106));
+    /// // 0.25 到 0 与 1/2 的距离相等，取分母较小的 0
+    /// assert_eq!(Fraction::best_rational(0.25, 2), Fraction::ZERO);
+    /// assert_eq!(Fraction::best_rational(-0.75, 2), Fraction::from(-1));
+    /// ```
+    pub fn best_rational(x: f64, max_denom: i32) -> Self {
+        assert!(max_denom > 0, "denominator cap must be positive");
+        if !x.is_finite() {
+            return Self::approximate_f64(x);
+        }
+
+        let (sign, mant, exp) = Self::decompose_f64(x);
+        if exp >= 0 {
+            return Self::approximate_f64(x);
+        }
+        let shift = -exp;
+        let (nume, deno) = if shift > 90 {
+            ((mant as u128) >> (shift - 90).min(127), 1u128 << 90)
+        } else {
+            (mant as u128, 1u128 << shift)
+        };
+        if nume == 0 {
+            return Self::ZERO;
+        }
+        let gcd = Self::gcd(nume, deno);
+        let (nume, deno) = (nume / gcd, deno / gcd);
+
+        let candidates = Self::bounding_candidates(nume, deno, Self::LIMITER, max_denom as u128);
+        let (nume, deno) = Self::closest_candidate(nume, deno, candidates);
+        Self::from_unsigned(sign, nume, deno)
+    }
+
+    /// 与浮点数误差不超过 `max_error` 的最简分数（分母最小者）
+    ///
+    /// 以分母上限二分查找 [`best_rational`](Self::best_rational)：分母上限增大时最佳近似的误差不增，
+    /// 因此满足误差的最小上限即为所求分数的分母。误差以结果的 `f64` 值计算。
+    /// 可以按需要的精度生成常数的有理近似，如 π 在误差 `2e-3` 内为 `22/7`，在 `1e-6` 内为 `355/113`
+    ///
+    /// # 参数
+    /// - `value`: 目标值
+    /// - `max_error`: 允许的最大误差
+    ///
+    /// # 返回值
+    /// `Self`，`i32` 范围内无法满足误差时返回分母不超过 `i32::MAX` 的最佳近似；
+    /// `NaN` 与无穷与 `best_rational` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::f64::consts::{E, PI};
+    /// 
+    /// assert_eq!(Fraction::approx_constant(PI, 2e-3), Fraction::new(22, 7));
+    /// assert_eq!(Fraction::approx_constant(PI, 1e-6), Fraction::new(355, 113));
+    /// assert_eq!(Fraction::approx_constant(E, 1e-3), Fraction::new(87, 32));
+    /// // 22/7 的误差约为 1.26e-3，不满足 1e-3
+    /// assert_eq!(Fraction::approx_constant(PI, 1e-3), Fraction::new(201, 64));
+    /// ```
+    pub fn approx_constant(value: f64, max_error: f64) -> Self {
+        let within = |x: Self| (x.to_f64() - value).abs() <= max_error;
+        let best = Self::best_rational(value, i32::MAX);
+        if !value.is_finite() || !within(best) {
+            return best;
+        }
+
+        let (mut lo, mut hi) = (1, i32::MAX);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if within(Self::best_rational(value, mid)) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Self::best_rational(value, hi)
+    }
+
+    /// 自然对数的有理近似
+    ///
+    /// 对数一般为无理数，结果为 `i32` 范围内的最佳有理近似而非精确值。全程以整数精确运算，不经过 `f64`：
+    /// 1. 将 `self` 精确地写为 `m · 2^k`，其中 `m` 在 `[70/99, 99/70]` 内（约为 `[1/√2, √2]`）
+    /// 2. 计算 `ln m = 2·atanh(u) = 2u·(1 + u^2/3 + u^4/5 + ...)`，`u = (m - 1) / (m + 1)` 满足 `|u| < 0.1716`。
+    ///    `u` 以规格化的定点数精确求出，级数以 `2^-62` 为单位的定点数截断到项小于一个单位，
+    ///    因此 `ln m` 的相对误差约为 `2^-58`，`m` 接近 `1` 时同样成立
+    /// 3. 加上 `k · ln 2`（`ln 2` 的误差不超过 `2^-95`），再将定点数结果 shrink 到 `i32` 范围
+    ///
+    /// shrink 之前的绝对误差小于 `1e-17`，`self` 接近 `1` 时相对误差小于 `1e-17`
+    /// （不会像先转换为 `f64` 那样丢失 `self - 1` 的低位）。
+    /// shrink 选取分子分母均不超过 `i32::MAX` 的最接近的分数，引入的误差不超过 `max(1, y^2) / i32::MAX`，
+    /// 其中 `y` 为对数值（`|y|` 不超过约 `21.5`；`|y|` 小于 `MIN_POSITIVE` 时结果为 `ZERO`）。
+    /// 总的绝对误差不超过 `1e-17 + max(1, y^2) / i32::MAX`。
+    /// 结果很小时相对误差可能较大，这是 `i32` 范围内的分数所能达到的最佳精度
+    ///
+    /// # 返回值
+    /// `Option<Self>`，非正数与 NaN 返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(1).ln(), Some(Fraction::ZERO));
+    /// let ln2 = Fraction::from(2).ln().unwrap();
+    /// assert!((f64::from(ln2) - 2f64.ln()).abs() < 1e-15);
+    ///
+    /// // 接近 1 时仍然精确
+    /// let x = Fraction::new(1000000001, 1000000000).ln().unwrap();
+    /// assert!((f64::from(x) - 1e-9).abs() < 1e-18);
+    ///
+    /// assert_eq!(Fraction::ZERO.ln(), None);
+    /// assert_eq!(Fraction::new(-1, 2).ln(), None);
+    /// assert!(Fraction::INFINITY.ln().unwrap().is_infinity());
+    /// ```
+    pub fn ln(&self) -> Option<Self> {
+        self.log_with(1 << Self::FIXED_BITS, Self::LN_2_WIDE)
+    }
+
+    /// 以 2 为底的对数的有理近似
+    ///
+    /// 计算方法与误差与 [`ln`](Self::ln) 相同，`log2(m · 2^k) = k + ln m / ln 2`，`self` 为 2 的幂时结果精确
+    ///
+    /// # 返回值
+    /// `Option<Self>`，非正数与 NaN 返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(8).log2(), Some(Fraction::from(3)));
+    /// assert_eq!(Fraction::new(1, 4).log2(), Some(Fraction::from(-2)));
+    /// assert_eq!(Fraction::NAN.log2(), None);
+    /// ```
+    pub fn log2(&self) -> Option<Self> {
+        self.log_with(Self::LN_2_FIXED, 1 << 94)
+    }
+
+    /// 以 10 为底的对数的有理近似
+    ///
+    /// 计算方法与误差与 [`ln`](Self::ln) 相同，`log10(m · 2^k) = k · log10(2) + ln m / ln 10`
+    ///
+    /// # 返回值
+    /// `Option<Self>`，非正数与 NaN 返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::from(1000).log10(), Some(Fraction::from(3)));
+    /// assert_eq!(Fraction::NEG_INFINITY.log10(), None);
+    /// ```
+    pub fn log10(&self) -> Option<Self> {
+        self.log_with(Self::LN_10_FIXED, Self::LOG10_2_WIDE)
+    }
+
+    /// 指数函数 `e^self` 的有理近似
+    ///
+    /// 全程以整数精确运算，不经过 `f64`：
+    /// 1. 范围缩减：以 `2^-94` 为单位的定点数表示 `self` 与 `ln 2`，取 `k` 为 `self / ln 2` 最接近的整数，
+    ///    余数 `r = self - k · ln 2` 满足 `|r| <= ln 2 / 2`
+    /// 2. 以 `2^-62` 为单位的定点数计算截断的 Taylor 级数 `1 + r + r^2/2! + ...`，直到项小于一个单位
+    /// 3. 乘以 `2^k`（精确），再 shrink 到 `i32` 范围
+    ///
+    /// shrink 之前的相对误差小于 `1e-17`。shrink 选取分子分母均不超过 `i32::MAX` 的最接近的分数，
+    /// 引入的误差不超过 `max(1, y^2) / i32::MAX` 与 `1/2` 中的较小者，其中 `y = e^self`。
+    ///
+    /// 结果超出范围时饱和：
+    /// - `self` 大于约 `21.4876` (`ln(i32::MAX)`) 时返回 `INFINITY`
+    /// - `self` 小于约 `-21.4876` (`-ln(i32::MAX)`) 时结果小于 `MIN_POSITIVE`，返回 `ZERO`
+    ///
+    /// # 返回值
+    /// `Self`，`exp(0)` 精确为 `1`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::ZERO.exp(), Fraction::from(1));
+    /// let e = Fraction::from(1).exp();
+    /// assert!((f64::from(e) - std::f64::consts::E).abs() < 1e-15);
+    ///
+    /// assert!(Fraction::from(22).exp().is_infinity());
+    /// assert!(Fraction::from(-23).exp().is_zero());
+    /// assert!(Fraction::NEG_INFINITY.exp().is_zero());
+    /// assert!(Fraction::NAN.exp().is_nan());
+    /// ```
+    pub fn exp(&self) -> Self {
+        match self.frac_type {
+            Type::NaN => return Self::NAN, 
+            Type::Infinity => return Self::INFINITY, 
+            Type::NegInfinity => return Self::ZERO, 
+            Type::Zero => return Self::ONE, 
+            Type::Normal => {}
+        }
+        // e^22 > i32::MAX，e^-22 < MIN_POSITIVE
+        if *self > Self::from(22) {
+            return Self::INFINITY;
+        } else if *self < Self::from(-22) {
+            return Self::ZERO;
+        }
+
+        // |self| <= 22，以 2^-94 为单位时小于 2^99；|k| <= 32
+        let wide = ((self.nume as i128) << 94) / self.deno as i128;
+        let k = Self::round_half_even(wide, Self::LN_2_WIDE);
+        let r = (wide - k * Self::LN_2_WIDE + (1 << 31)) >> 32;
+
+        // r^n / n! 的 Taylor 级数，依次累加直到项为 0
+        let (mut sum, mut term, mut n) = (0, 1 << Self::FIXED_BITS, 1);
+        while term != 0 {
+            sum += term;
+            term = Self::fixed_mul(term, r) / n;
+            n += 1;
+        }
+
+        // sum < 2^62.5，乘以 2^k 后分子与分母均小于 2^95
+        if k >= 0 {
+            Self::from_i128(sum << k, 1 << Self::FIXED_BITS)
+        } else {
+            Self::from_i128(sum, 1 << (Self::FIXED_BITS as i128 - k))
+        }
+    }
+
+    /// 正弦函数 `sin(self)` 的有理近似（弧度）
+    ///
+    /// 全程以整数精确运算，不经过 `f64`：
+    /// 1. 范围缩减：以 `2^-94` 为单位的定点数表示 `self` 与 `π/2`，取 `k` 为 `self / (π/2)` 最接近的整数，
+    ///    余数 `r = self - k·π/2` 满足 `|r| <= π/4`。`π/2` 的误差不超过 `2^-95`，
+    ///    在整个 `i32` 范围内（`|k| < 2^31`）余数的误差小于 `2^-62`
+    /// 2. 以 `2^-62` 为单位的定点数对 `r` 计算截断的 Taylor 级数 `r - r^3/3! + r^5/5! - ...`，
+    ///    直到项小于一个单位；再按 `k mod 4` 选取 `±sin r` 或 `±cos r`
+    /// 3. 将定点数结果 shrink 到 `i32` 范围（相当于 `limit_denominator(i32::MAX)`）
+    ///
+    /// shrink 之前的误差小于 `1e-17`；shrink 选取分母不超过 `i32::MAX` 的最接近的分数，
+    /// 引入的误差不超过 `1 / (2 * i32::MAX)`（约 `2.3e-10`，仅在结果十分接近分母较小的分数如 `±1`、`1/2` 时达到，
+    /// 一般在 `1e-18` 左右）。总误差不超过 `1e-17 + 1 / (2 * i32::MAX)`
+    ///
+    /// # 返回值
+    /// `Self`，`sin(0)` 精确为 `ZERO`，无穷与 `NAN` 返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::ZERO.sin().is_zero());
+    /// let x = Fraction::new(1, 2).sin();
+    /// assert!((f64::from(x) - 0.5f64.sin()).abs() < 1e-15);
+    /// assert!(Fraction::INFINITY.sin().is_nan());
+    /// ```
+    pub fn sin(&self) -> Self {
+        self.sin_cos_with(|quadrant, sin, cos| [sin, cos, -sin, -cos][quadrant])
+    }
+
+    /// 余弦函数 `cos(self)` 的有理近似（弧度）
+    ///
+    /// 范围缩减与误差与 [`sin`](Self::sin) 相同
+    ///
+    /// # 返回值
+    /// `Self`，`cos(0)` 精确为 `1`，无穷与 `NAN` 返回 `NAN`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::ZERO.cos(), Fraction::from(1));
+    /// let x = Fraction::new(22, 7).cos();
+    /// assert!((f64::from(x) - (22f64 / 7.0).cos()).abs() < 1e-15);
+    /// assert!(Fraction::NAN.cos().is_nan());
+    /// ```
+    pub fn cos(&self) -> Self {
+        self.sin_cos_with(|quadrant, sin, cos| [cos, -sin, -cos, sin][quadrant])
     }
 
-    /// 非特数值（不包括零值）返回 true，否则返回 false
+    /// 反正切函数 `atan(self)` 的有理近似（弧度）
+    ///
+    /// 与 `atan2(self, 1)` 相同，计算方法与误差见 [`atan2`](Self::atan2)
     ///
     /// # 返回值
-    /// `bool`
+    /// `Self`，在 `[-PI/2, PI/2]` 内，`atan(0)` 精确为 `ZERO`，`NAN` 返回 `NAN`
     ///
     /// # 示例
     /// ```
     /// # use fraction::Fraction;
     /// 
-    /// let a1 = Fraction::new(2, 3);
-    /// assert!(a1.is_normal());
-    /// let a2 = Fraction::new(-2, 3);
-    /// assert!(a2.is_normal());
-    ///
-    /// let zero = Fraction::ZERO;
-    /// assert!(!zero.is_normal());
-    /// let nan = Fraction::NAN;
-    /// assert!(!nan.is_normal());
-    /// let inf = Fraction::INFINITY;
-    /// assert!(!inf.is_normal());
-    /// let neg_inf = Fraction::NEG_INFINITY;
-    /// assert!(!neg_inf.is_normal());
+    /// assert!(Fraction::ZERO.atan().is_zero());
+    /// let x = Fraction::from(1).atan();
+    /// assert!((f64::from(x) - std::f64::consts::FRAC_PI_4).abs() < 1e-15);
+    /// assert!((f64::from(Fraction::INFINITY.atan()) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
     /// ```
-    pub fn is_normal(&self) -> bool {
-        self.frac_type == Type::Normal
+    pub fn atan(&self) -> Self {
+        Self::atan2(*self, Self::ONE)
     }
 
-    /// 获取绝对值，保持特殊值语义
+    /// 点 `(x, y)` 的辐角 `atan2(y, x)` 的有理近似（弧度）
+    ///
+    /// 全程以整数精确运算，不经过 `f64`：
+    /// 1. 精确计算 `t = |y| / |x|`（分子分母均为 `i128`），`t > 1` 时利用 `atan t = π/2 - atan(1/t)` 换为 `t <= 1`
+    /// 2. `t > 29/70`（略大于 `tan(π/8)`）时利用 `atan t = π/4 + atan((t - 1) / (t + 1))` 继续缩减，
+    ///    级数的参数 `u` 满足 `|u| < 0.4143`
+    /// 3. 以 `2^-62` 为单位的定点数计算截断的级数 `u - u^3/3 + u^5/5 - ...`，直到项小于一个单位
+    /// 4. 按 `x`、`y` 的符号调整象限，再将定点数结果 shrink 到 `i32` 范围
+    ///
+    /// shrink 之前的误差小于 `1e-17`；shrink 选取分子分母均不超过 `i32::MAX` 的最接近的分数，
+    /// 结果的绝对值大于 `1` 时受分子限制，引入的误差不超过 `max(1, angle^2) / (2 * i32::MAX)`
+    /// （在 `±π` 附近约 `2.3e-9`，仅在结果十分接近分母较小的分数时达到，一般在 `1e-17` 以下）。
+    /// 总误差不超过 `1e-17 + max(1, angle^2) / (2 * i32::MAX)`。
+    /// 结果限制在 `[-PI, PI]` 内，其中 [`PI`](Self::PI) 为本类型的常量
+    ///
+    /// 参数为无穷时与 `f64::atan2` 相同：无穷视为 `±1`，此时另一个有限的参数视为 `0`
+    ///
+    /// # 参数
+    /// * `y` - 纵坐标
+    /// * `x` - 横坐标
     ///
     /// # 返回值
-    /// `Self`
+    /// `Self`，`atan2(0, x)` 在 `x >= 0` 时为 `ZERO`，在 `x < 0` 时为 `PI`；
+    /// 任一参数为 `NAN` 时返回 `NAN`
     ///
     /// # 示例
     /// ```
     /// # use fraction::Fraction;
     /// 
-    /// let a = Fraction::new(-2, 3);
-    /// assert_eq!(a.abs(), Fraction::new(2, 3));
-    ///
-    /// let inf = Fraction::INFINITY;
-    /// assert_eq!(inf.abs(), Fraction::INFINITY);
+    /// let one = Fraction::from(1);
+    /// assert!(Fraction::atan2(Fraction::ZERO, one).is_zero());
+    /// assert_eq!(Fraction::atan2(Fraction::ZERO, -one), Fraction::PI);
+    /// let half_pi = Fraction::atan2(one, Fraction::ZERO);
+    /// assert!((f64::from(half_pi) - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
     /// ```
-    pub fn abs(&self) -> Self {
+    pub fn atan2(y: Self, x: Self) -> Self {
+        if y.is_nan() || x.is_nan() {
+            return Self::NAN;
+        }
+        let infinite = |v: Self| v.is_infinity() || v.is_neg_infinity();
+        let (y, x) = if infinite(y) || infinite(x) {
+            let unit = |v: Self| if infinite(v) { Self::from(v.i32_sign()) } else { Self::ZERO };
+            (unit(y), unit(x))
+        } else {
+            (y, x)
+        };
+        if y.is_zero() {
+            return if x.is_negative() { Self::PI } else { Self::ZERO };
+        }
+
+        // |y| / |x| = p / q，q 为 0 时为 π/2
+        let p = y.nume.unsigned_abs() as i128 * x.deno as i128;
+        let q = y.deno as i128 * x.nume.unsigned_abs() as i128;
+        let angle = if p <= q { Self::fixed_atan(p, q) } else { Self::FRAC_PI_2_FIXED - Self::fixed_atan(q, p) };
+        let angle = if x.is_negative() { Self::PI_FIXED - angle } else { angle };
+        let angle = Self::from_fixed(if y.is_negative() { -angle } else { angle });
+
+        if angle > Self::PI {
+            Self::PI
+        } else if angle < -Self::PI {
+            -Self::PI
+        } else {
+            angle
+        }
+    }
+
+    // 以 ln(base) 的定点数 base_ln 与 ln(2) / ln(base) 的 2^-94 定点数 k_scale 计算 log_base(self)
+    fn log_with(&self, base_ln: i128, k_scale: i128) -> Option<Self> {
         match self.frac_type {
-            Type::NegInfinity | Type::Infinity => Self::INFINITY, 
-            Type::NaN => Self::NAN, 
-            Type::Zero => Self::ZERO, 
-            _ => Self {
-                nume: self.nume.abs(), 
-                deno: self.deno, 
-                frac_type: self.frac_type
-            }
+            Type::Infinity => return Some(Self::INFINITY), 
+            Type::Normal if self.nume > 0 => {}, 
+            _ => return None
+        }
+
+        // self = (p / q) · 2^k，p / q 在 [70/99, 99/70] 内，p、q 均小于 2^63
+        let (a, b) = (self.nume as i128, self.deno as i128);
+        let mut k = a.ilog2() as i32 - b.ilog2() as i32;
+        let (mut p, mut q) = if k >= 0 { (a, b << k) } else { (a << -k, b) };
+        if 70 * p > 99 * q {
+            k += 1;
+            q <<= 1;
+        } else if 99 * p < 70 * q {
+            k -= 1;
+            p <<= 1;
+        }
+
+        // ln(p / q) 以 2^-(FIXED_BITS + e) 为单位
+        let (ln_m, e) = Self::fixed_ln(p, q);
+        let ln_m = (ln_m << Self::FIXED_BITS) / base_ln;
+        if k == 0 {
+            Some(Self::from_i128(ln_m, 1 << (Self::FIXED_BITS + e)))
+        } else {
+            Some(Self::from_fixed(((k as i128 * k_scale + (1 << 31)) >> 32) + (ln_m >> e)))
         }
     }
 
-    /// 获取倒数，保持特殊值语义
+    // 计算 ln(p / q)，要求 p / q 在 [70/99, 99/70] 内且 p、q 均小于 2^63。
+    // 返回 (v, e)，结果为 v · 2^-(FIXED_BITS + e)。p / q 接近 1 时 e 较大，以保持相对精度
+    fn fixed_ln(p: i128, q: i128) -> (i128, u32) {
+        if p == q {
+            return (0, 0);
+        }
+
+        // ln(p / q) = 2u·(1 + u^2/3 + u^4/5 + ...)，u = (p - q) / (p + q)，|u| < 0.1716。
+        // 以 u · 2^(FIXED_BITS + e) 表示 u，使其在 [2^60, 2^62] 内；e 不超过 32，保证最终的分母小于 2^95
+        let (diff, sum) = ((p - q).abs(), p + q);
+        let e = (sum.ilog2() - diff.ilog2() - 1).min(32);
+        let u = (diff << (Self::FIXED_BITS + e)) / sum;
+
+        let u_2 = Self::fixed_mul(u, u) >> (2 * e);
+        let (mut series, mut power, mut k) = (0, 1 << Self::FIXED_BITS, 1);
+        while power != 0 {
+            series += power / k;
+            power = Self::fixed_mul(power, u_2);
+            k += 2;
+        }
+        ((p - q).signum() * 2 * Self::fixed_mul(u, series), e)
+    }
+
+    // 超越函数以 i128 定点数计算，单位为 2^-FIXED_BITS
+    const FIXED_BITS: u32 = 62;
+    // round(π/2 · 2^94)，用于范围缩减
+    const FRAC_PI_2_WIDE: i128 = 0x6487ed5110b4611a62633146;
+
+    // round(ln 2 · 2^94)、round(log10(2) · 2^94)，用于对数与指数函数中的 k · ln 2 一项
+    const LN_2_WIDE: i128 = 0x2c5c85fdf473de6af278ece6;
+    const LOG10_2_WIDE: i128 = 0x134413509f79fef311f12b36;
+    // ln 2、ln 10 的定点数
+    const LN_2_FIXED: i128 = (Self::LN_2_WIDE + (1 << 31)) >> 32;
+    const LN_10_FIXED: i128 = 0x935d8dddaaa8ac17;
+
+    // π、π/2、π/4 的定点数
+    const PI_FIXED: i128 = (Self::FRAC_PI_2_WIDE + (1 << 30)) >> 31;
+    const FRAC_PI_2_FIXED: i128 = (Self::FRAC_PI_2_WIDE + (1 << 31)) >> 32;
+    const FRAC_PI_4_FIXED: i128 = (Self::FRAC_PI_2_WIDE + (1 << 32)) >> 33;
+
+    // 定点数乘法，要求两者的绝对值均小于 2^64
+    fn fixed_mul(a: i128, b: i128) -> i128 {
+        (a * b) >> Self::FIXED_BITS
+    }
+
+    // 定点数 shrink 为分数
+    fn from_fixed(value: i128) -> Self {
+        Self::from_i128(value, 1 << Self::FIXED_BITS)
+    }
+
+    // 以定点数计算 atan(nume / deno)，要求 0 <= nume <= deno < 2^63
+    fn fixed_atan(nume: i128, deno: i128) -> i128 {
+        // 29/70 略大于 tan(π/8)，缩减后 |u| < 0.4143
+        let (u, base) = if 70 * nume <= 29 * deno {
+            ((nume << Self::FIXED_BITS) / deno, 0)
+        } else {
+            (((nume - deno) << Self::FIXED_BITS) / (nume + deno), Self::FRAC_PI_4_FIXED)
+        };
+
+        // u - u^3/3 + u^5/5 - ...，依次累加直到项为 0
+        let u_2 = Self::fixed_mul(u, u);
+        let (mut sum, mut power, mut k) = (0, u, 1);
+        while power != 0 {
+            sum += power / k;
+            power = -Self::fixed_mul(power, u_2);
+            k += 2;
+        }
+        base + sum
+    }
+
+    // 将 self 缩减为 k·π/2 + r，|r| <= π/4，以 (k mod 4, sin r, cos r) 调用 select，结果均为定点数
+    fn sin_cos_with(&self, select: fn(usize, i128, i128) -> i128) -> Self {
+        match self.frac_type {
+            Type::Normal | Type::Zero => {}, 
+            _ => return Self::NAN
+        }
+
+        // |nume| < 2^31，以 2^-94 为单位时不超过 2^125；|k| < 2^31 时 k·(π/2) 不超过 2^126
+        let wide = ((self.nume as i128) << 94) / self.deno as i128;
+        let k = Self::round_half_even(wide, Self::FRAC_PI_2_WIDE);
+        let r = (wide - k * Self::FRAC_PI_2_WIDE + (1 << 31)) >> 32;
+
+        // r^n / n! 的 Taylor 级数，依次累加直到项为 0
+        let r_2 = Self::fixed_mul(r, r);
+        let (mut sin, mut cos) = (0, 0);
+        let (mut sin_term, mut cos_term) = (r, 1 << Self::FIXED_BITS);
+        let mut n = 0;
+        while sin_term != 0 || cos_term != 0 {
+            sin += sin_term;
+            cos += cos_term;
+            sin_term = -Self::fixed_mul(sin_term, r_2) / ((2 * n + 2) * (2 * n + 3));
+            cos_term = -Self::fixed_mul(cos_term, r_2) / ((2 * n + 1) * (2 * n + 2));
+            n += 1;
+        }
+        Self::from_fixed(select(k.rem_euclid(4) as usize, sin, cos))
+    }
+
+    /// 融合乘加，计算 `self * a + b`
+    ///
+    /// 中间乘积以 `i128` 完整保留，仅在最终结果上进行一次 shrink，
+    /// 而 `self * a + b` 会在乘法和加法之后各 shrink 一次。
+    /// 在结果超出 `i32` 范围的长链计算（如 Horner 法求多项式值）中可以减少累积误差。
+    ///
+    /// 特殊值按先乘后加的规则处理
     ///
     /// # 返回值
     /// `Self`
@@ -512,24 +4516,67 @@ impl Fraction {
     /// ```
     /// # use fraction::Fraction;
     /// 
+    /// let x = Fraction::new(1, 2);
     /// let a = Fraction::new(2, 3);
-    /// assert_eq!(a.reciprocal(), Fraction::new(3, 2));
+    /// let b = Fraction::new(1, 6);
+    /// assert_eq!(x.mul_add(a, b), Fraction::new(1, 2));
+    ///
+    /// // 中间结果超出范围时，mul_add 只在最后 shrink 一次
+    /// let x = Fraction::new(1000003, 999983);
+    /// let c = Fraction::new(7, 1000033);
+    /// assert_eq!(x.mul_add(x, c), Fraction::new(1433041465, 1432974114));
+    /// assert_eq!(x * x + c, Fraction::new(2062400101, 2062303171));
     ///
     /// let inf = Fraction::INFINITY;
-    /// assert!(inf.reciprocal().is_zero());
+    /// let zero = Fraction::ZERO;
+    /// assert!(inf.mul_add(zero, a).is_nan());
+    /// assert!(x.mul_add(a, inf).is_infinity());
     /// ```
-    pub fn reciprocal(&self) -> Self {
-        match self.frac_type {
-            Type::Infinity => Self::ZERO, 
-            Type::NegInfinity => Self::ZERO, 
-            Type::NaN => Self::NAN, 
-            Type::Zero => Self::INFINITY, 
-            _ => Self { 
-                nume: self.deno.abs() * self.i32_sign(), 
-                deno: self.nume.abs(), 
-                frac_type: self.frac_type
-            }
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        match (self.get_mul_type(a), b.frac_type) {
+            (Type::Normal, Type::Normal) => {
+                let (nume, deno) = self.normal_mul_add(a, b);
+                Self {
+                    nume, 
+                    deno, 
+                    frac_type: Self::determine_frac_type(nume, deno)
+                }
+            }, 
+            _ => self * a + b
+        }
+    }
+
+    /// 乘加运算 `self * a + b`，任何一步无法精确表示时返回 `None`
+    ///
+    /// 以 `i128` 精确计算，要求乘积与最终结果化简后都能以 `i32` 精确表示，
+    /// 即与依次进行的精确乘法与精确加法相同。`Some` 的结果保证没有经过 shrink，
+    /// 适合需要确保精确的 Horner 多项式求值
+    ///
+    /// # 返回值
+    /// `Option<Self>`，任一操作数为 `NAN` 或无穷，或某一步需要 shrink 时返回 `None`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// // 2x^2 - 3x + 1/2 在 x = 3/4 处的值
+    /// let x = Fraction::new(3, 4);
+    /// let y = Fraction::from(2).checked_mul_add(x, Fraction::from(-3))
+    ///     .and_then(|y| y.checked_mul_add(x, Fraction::new(1, 2)));
+    /// assert_eq!(y, Some(Fraction::new(-5, 8)));
+    ///
+    /// let tiny = Fraction::new(1, 65537);
+    /// assert_eq!(tiny.checked_mul_add(tiny, Fraction::ONE), None);
+    /// ```
+    pub fn checked_mul_add(self, a: Self, b: Self) -> Option<Self> {
+        if [self, a, b].iter().any(|x| !matches!(x.frac_type, Type::Normal | Type::Zero)) {
+            return None;
         }
+
+        let (p, q) = (self.nume as i128 * a.nume as i128, self.deno as i128 * a.deno as i128);
+        Self::checked_from_i128(p, q)?;
+        let (e, f) = (b.nume as i128, b.deno as i128);
+        Self::checked_from_i128(p * f + e * q, q * f)
     }
 
     // operations
@@ -551,9 +4598,45 @@ impl Fraction {
         }
     }
 
+    // 减去 ±inf 即加上 ∓inf，有限值取相反数后类型不变
+    fn get_sub_type(self, rhs: Self) -> Type {
+        let rhs = match rhs.frac_type {
+            Type::Infinity => Self::NEG_INFINITY, 
+            Type::NegInfinity => Self::INFINITY, 
+            _ => rhs
+        };
+        self.get_add_type(rhs)
+    }
+
     fn normal_add(self, rhs: Self) -> (i32, i32) {
-        let (a, b) = (self.nume as i64, self.deno as i64);
-        let (c, d) = (rhs.nume as i64, rhs.deno as i64);
+        let (sign, nume, deno) = self.exact_add(rhs);
+        let (num, den) = Self::shrink(nume as u128, deno as u128);
+
+        (num as i32 * sign, den as i32)
+    }
+
+    fn normal_sub(self, rhs: Self) -> (i32, i32) {
+        let (sign, nume, deno) = self.exact_sub(rhs);
+        let (num, den) = Self::shrink(nume as u128, deno as u128);
+
+        (num as i32 * sign, den as i32)
+    }
+
+    // 两个有限值的精确和，返回化简后的 (符号, 分子绝对值, 分母)
+    fn exact_add(self, rhs: Self) -> (i32, u64, u64) {
+        self.exact_add_parts(rhs.nume as i128, rhs.deno as i128)
+    }
+
+    // 两个有限值的精确差。减数在 i128 中取相反数，
+    // MIN 的相反数 i32::MAX/1 不能表示为有限的 Fraction，但在此处可以精确参与运算
+    fn exact_sub(self, rhs: Self) -> (i32, u64, u64) {
+        self.exact_add_parts(-(rhs.nume as i128), rhs.deno as i128)
+    }
+
+    // self + c/d，要求 |c| <= i32::MAX、0 < d <= i32::MAX
+    // 中间结果以 i128 计算：|a * e + c * f| 最大约为 2^63，在 i64 中没有余量
+    fn exact_add_parts(self, c: i128, d: i128) -> (i32, u64, u64) {
+        let (a, b) = (self.nume as i128, self.deno as i128);
 
         let (e, f, gcd_bd) = Self::lcm(b, d);
         let (nume, deno) = (
@@ -561,14 +4644,11 @@ impl Fraction {
         );
 
         let sign = nume.signum() as i32;
-        let (u_num, u_den) = (nume.unsigned_abs(), deno as u64);
+        let (u_num, u_den) = (nume.unsigned_abs(), deno as u128);
 
         let gcd = Self::gcd(u_num, u_den);
-        let (simplified_num, simplified_den) = (u_num / gcd, u_den / gcd);
-
-        let (num, den) = Self::shrink(simplified_num, simplified_den);
-
-        (num as i32 * sign, den as i32)
+        // 分子分母均不超过 2^63，化简后可以放入 u64
+        (sign, (u_num / gcd) as u64, (u_den / gcd) as u64)
     }
 
     fn get_mul_type(self, rhs: Self) -> Type {
@@ -614,10 +4694,174 @@ impl Fraction {
 
         let (nume, deno) = (a * c, b * d);
         // println!("mul_impl {} {}", nume, deno);
-        let (nume, deno) = Self::shrink(nume, deno);
+        let (nume, deno) = Self::shrink(nume as u128, deno as u128);
         
         (nume as i32 * self.i32_sign() * rhs.i32_sign(), deno as i32)
     }
+
+    // nume / deno 四舍六入五成双，要求 deno > 0
+    fn round_half_even(nume: i128, deno: i128) -> i128 {
+        let (q, r) = (nume.div_euclid(deno), nume.rem_euclid(deno));
+        match (2 * r).cmp(&deno) {
+            Ordering::Less => q, 
+            Ordering::Greater => q + 1, 
+            Ordering::Equal => q + q.rem_euclid(2)
+        }
+    }
+
+    // 精确的整数 n 次方根，x 不是完全 n 次方时返回 None
+    fn nth_root(x: u128, n: u32) -> Option<u128> {
+        if n == 1 || x < 2 {
+            return Some(x);
+        }
+        let (mut lo, mut hi) = (1u128, 1u128 << (128 / n + 1).min(127));
+        while lo <= hi {
+            let mid = lo + (hi - lo) / 2;
+            match mid.checked_pow(n).map(|v| v.cmp(&x)) {
+                Some(Ordering::Equal) => return Some(mid), 
+                Some(Ordering::Less) => lo = mid + 1, 
+                _ => hi = mid - 1
+            }
+        }
+        None
+    }
+
+    // 与有限值相同默认右对齐，但忽略 `0` 标志与精度，始终使用填充字符
+    fn pad_non_finite(f: &mut Formatter, s: &str) -> fmt::Result {
+        let padding = f.width().unwrap_or(0).saturating_sub(s.len());
+        let (pre, post) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding), 
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2), 
+            _ => (padding, 0)
+        };
+        let fill = f.fill();
+        (0..pre).try_for_each(|_| write!(f, "{fill}"))?;
+        f.write_str(s)?;
+        (0..post).try_for_each(|_| write!(f, "{fill}"))
+    }
+
+    // 连分数展开的各项（首项向下取整），特殊值（零除外）为空
+    fn cf_terms(&self) -> impl Iterator<Item = i64> {
+        let (mut nume, mut deno) = match self.frac_type {
+            Type::Normal | Type::Zero => (self.nume as i64, self.deno as i64), 
+            _ => (0, 0)
+        };
+        std::iter::from_fn(move || {
+            if deno == 0 {
+                return None;
+            }
+            let q = nume.div_euclid(deno);
+            (nume, deno) = (deno, nume - q * deno);
+            Some(q)
+        })
+    }
+
+    // 由连分数的各项计算渐进分数，超过 2^94 时停止（此时误差远小于 shrink 的误差）
+    fn from_cf_terms(terms: impl Iterator<Item = i128>) -> Self {
+        const BOUND: i128 = 1 << 94;
+        let (mut p_0, mut q_0, mut p_1, mut q_1) = (0i128, 1i128, 1i128, 0i128);
+        for a in terms {
+            let (p_2, q_2) = (a * p_1 + p_0, a * q_1 + q_0);
+            if p_2.abs() > BOUND || q_2.abs() > BOUND {
+                break;
+            }
+            (p_0, q_0, p_1, q_1) = (p_1, q_1, p_2, q_2);
+        }
+
+        let sign = if q_1 < 0 { -p_1.signum() } else { p_1.signum() } as i32;
+        Self::from_unsigned(sign, p_1.unsigned_abs(), q_1.unsigned_abs())
+    }
+
+    // 将有限 f64 分解为 (符号, 尾数, 指数)，满足 x = sign * mant * 2^exp
+    fn decompose_f64(x: f64) -> (i32, u64, i32) {
+        let bits = x.to_bits();
+        let sign = if bits >> 63 == 0 { 1 } else { -1 };
+        let exp_bits = ((bits >> 52) & 0x7ff) as i32;
+        let frac = bits & ((1 << 52) - 1);
+        if exp_bits == 0 {
+            (sign, frac, -1074)
+        } else {
+            (sign, frac | (1 << 52), exp_bits - 1075)
+        }
+    }
+
+    // 将 f64 的精确值 shrink 为 i32 范围内的最佳有理近似
+    // 低于 2^-90 的二进制位会被截断，其影响远小于 shrink 本身的误差
+    fn approximate_f64(x: f64) -> Self {
+        if x.is_nan() {
+            return Self::NAN;
+        } else if x.is_infinite() {
+            return if x > 0.0 { Self::INFINITY } else { Self::NEG_INFINITY };
+        }
+
+        let (sign, mant, exp) = Self::decompose_f64(x);
+        if exp >= 0 {
+            if exp > 40 {
+                Self::from_unsigned(sign, 1, 0)
+            } else {
+                Self::from_unsigned(sign, (mant as u128) << exp, 1)
+            }
+        } else {
+            let shift = -exp;
+            let (mant, shift) = if shift > 90 {
+                ((mant as u128) >> (shift - 90).min(127), 90)
+            } else {
+                (mant as u128, shift)
+            };
+            Self::from_unsigned(sign, mant, 1 << shift)
+        }
+    }
+
+    fn cmp_f64(&self, other: f64) -> Option<Ordering> {
+        if !other.is_finite() {
+            return None;
+        }
+        match self.frac_type {
+            Type::NaN => return None, 
+            Type::Infinity => return Some(Ordering::Greater), 
+            Type::NegInfinity => return Some(Ordering::Less), 
+            _ => {}
+        }
+
+        let (sign, mant, exp) = Self::decompose_f64(other);
+        let other_sign = if mant == 0 { 0 } else { sign };
+        let self_sign = self.i32_sign();
+        if self_sign != other_sign || self_sign == 0 {
+            return Some(self_sign.cmp(&other_sign));
+        }
+
+        // |self| = a / b, |other| = mant * 2^exp
+        let (a, b) = (self.nume.unsigned_abs() as i128, self.deno as i128);
+        let mant = mant as i128;
+        let ordering = if exp >= 0 {
+            if exp > 40 {
+                Ordering::Less
+            } else {
+                a.cmp(&(b * (mant << exp)))
+            }
+        } else if exp < -94 {
+            Ordering::Greater
+        } else {
+            (a << -exp).cmp(&(b * mant))
+        };
+
+        Some(if self_sign > 0 { ordering } else { ordering.reverse() })
+    }
+
+    fn normal_mul_add(self, a: Self, b: Self) -> (i32, i32) {
+        let (p, q) = (self.nume as i128 * a.nume as i128, self.deno as i128 * a.deno as i128);
+        let (e, f) = (b.nume as i128, b.deno as i128);
+
+        let (nume, deno) = (p * f + e * q, q * f);
+
+        let sign = nume.signum() as i32;
+        let (u_num, u_den) = (nume.unsigned_abs(), deno as u128);
+
+        let gcd = Self::gcd(u_num, u_den);
+        let (num, den) = Self::shrink(u_num / gcd, u_den / gcd);
+
+        (num as i32 * sign, den as i32)
+    }
 }
 
 impl<T: Into<Fraction>> Add<T> for Fraction {
@@ -685,8 +4929,22 @@ impl<T: Into<Fraction>> Sub<T> for Fraction {
     /// assert!((a - nan).is_nan());
     /// ```
     fn sub(self, rhs: T) -> Self::Output {
-        let rhs: Self = -rhs.into();
-        self + rhs
+        let rhs: Self = rhs.into();
+        let sub_type = self.get_sub_type(rhs);
+        match sub_type {
+            Type::Infinity => Self::INFINITY, 
+            Type::NegInfinity => Self::NEG_INFINITY, 
+            Type::NaN => Self::NAN, 
+            Type::Zero => Self::ZERO, 
+            Type::Normal => {
+                let (nume, deno) = self.normal_sub(rhs);
+                Self { 
+                    nume, 
+                    deno, 
+                    frac_type: Self::determine_frac_type(nume, deno)
+                }
+            }
+        }
     }
 }
 
@@ -754,6 +5012,7 @@ impl<T: Into<Fraction>> Div<T> for Fraction {
     /// assert!((a / neg_inf).is_zero());
     /// assert!((a / nan).is_nan());
     /// ```
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: T) -> Self::Output {
         let rhs: Self = rhs.into().reciprocal();
         self * rhs
@@ -808,7 +5067,17 @@ impl<T: Into<Fraction>> SubAssign<T> for Fraction {
     /// ```
     fn sub_assign(&mut self, rhs: T) {
         let rhs: Self = rhs.into();
-        *self += -rhs;
+        let sub_type = self.get_sub_type(rhs);
+        match sub_type {
+            Type::Infinity => *self = Self::INFINITY, 
+            Type::NegInfinity => *self = Self::NEG_INFINITY, 
+            Type::NaN => *self = Self::NAN, 
+            Type::Zero => *self = Self::ZERO, 
+            Type::Normal => {
+                (self.nume, self.deno) = self.normal_sub(rhs);
+                self.frac_type = Self::determine_frac_type(self.nume, self.deno);
+            }
+        }
     }
 }
 
@@ -829,8 +5098,8 @@ impl<T: Into<Fraction>> MulAssign<T> for Fraction {
     /// ```
     fn mul_assign(&mut self, rhs: T) {
         let rhs: Self = rhs.into();
-        let add_type = self.get_add_type(rhs);
-        match add_type {
+        let mul_type = self.get_mul_type(rhs);
+        match mul_type {
             Type::Infinity => *self = Self::INFINITY, 
             Type::NegInfinity => *self = Self::NEG_INFINITY, 
             Type::NaN => *self = Self::NAN, 
@@ -858,6 +5127,7 @@ impl<T: Into<Fraction>> DivAssign<T> for Fraction {
     /// assert_eq!(a, Fraction::new(3, 2));
     /// assert_eq!(b, Fraction::new(1, 9));
     /// ```
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn div_assign(&mut self, rhs: T) {
         let rhs: Self = rhs.into();
         *self *= rhs.reciprocal();
@@ -884,20 +5154,80 @@ impl Neg for Fraction {
     /// assert!((-neg_inf).is_infinity());
     /// assert!((-zero).is_zero());
     /// ```
-    fn neg(self) -> Self::Output {
-        match self.frac_type {
-            Type::Infinity => Self::NEG_INFINITY, 
-            Type::NegInfinity => Self::INFINITY, 
-            Type::NaN => Self::NAN, 
-            Type::Zero => Self::ZERO, 
-            Type::Normal => {
-                Self {
-                    nume: -self.nume, 
-                    deno: self.deno, 
-                    frac_type: Type::Normal
-                }
-            }
-        }
+    fn neg(self) -> Self::Output {
+        match self.frac_type {
+            Type::Infinity => Self::NEG_INFINITY, 
+            Type::NegInfinity => Self::INFINITY, 
+            Type::NaN => Self::NAN, 
+            Type::Zero => Self::ZERO, 
+            // MIN 的相反数 i32::MAX/1 即为 INFINITY
+            Type::Normal if self.nume == Self::MIN.nume && self.deno == 1 => Self::INFINITY, 
+            Type::Normal => {
+                Self {
+                    nume: -self.nume, 
+                    deno: self.deno, 
+                    frac_type: Type::Normal
+                }
+            }
+        }
+    }
+}
+
+impl Neg for &Fraction {
+    type Output = Fraction;
+
+    /// 对引用取反，与 `-Fraction` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// let a = Fraction::new(1, 2);
+    /// assert_eq!(-&a, Fraction::new(-1, 2));
+    /// ```
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl Sum for Fraction {
+    /// 求和，等价于依次使用 `+` 累加，空迭代器返回 `ZERO`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// assert_eq!(values.into_iter().sum::<Fraction>(), Fraction::from(1));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, x| acc + x)
+    }
+}
+
+impl<'a> Sum<&'a Fraction> for Fraction {
+    /// 对引用求和，与 `Sum<Fraction>` 相同
+    fn sum<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Fraction {
+    /// 求积，等价于依次使用 `*` 累乘，空迭代器返回 `1`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// let values = [Fraction::new(2, 3), Fraction::new(3, 4), Fraction::from(2)];
+    /// assert_eq!(values.iter().product::<Fraction>(), Fraction::from(1));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::from(1), |acc, x| acc * x)
+    }
+}
+
+impl<'a> Product<&'a Fraction> for Fraction {
+    /// 对引用求积，与 `Product<Fraction>` 相同
+    fn product<I: Iterator<Item = &'a Fraction>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
@@ -920,22 +5250,162 @@ impl Display for Fraction {
     /// assert_eq!(neg_inf.to_string(), "-inf");
     /// assert_eq!(nan.to_string(), "nan");
     /// assert_eq!(zero.to_string(), "0");
+    ///
+    /// // 支持宽度、填充与对齐
+    /// assert_eq!(format!("{:>8}", Fraction::new(3, 4)), "     3/4");
+    /// assert_eq!(format!("{:*^7}", Fraction::new(3, 4)), "**3/4**");
+    /// assert_eq!(format!("{:<5}|", Fraction::NAN), "nan  |");
+    ///
+    /// // `+` 标志为非负数加上正号，NaN 没有符号
+    /// assert_eq!(format!("{:+}", Fraction::new(3, 4)), "+3/4");
+    /// assert_eq!(format!("{:+}", Fraction::ZERO), "+0");
+    /// assert_eq!(format!("{:+}", Fraction::INFINITY), "+inf");
+    /// assert_eq!(format!("{:+}", Fraction::NAN), "nan");
     /// ```
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // 有限值通过 pad_integral 输出，以支持宽度、填充字符、对齐方式、`+` 与 `0` 标志
         match self.frac_type {
-            Type::Infinity => write!(f, "inf"), 
-            Type::NegInfinity => write!(f, "-inf"), 
-            Type::NaN => write!(f, "nan"), 
-            Type::Zero => write!(f, "0"), 
-            Type::Normal => if self.deno == 1 {
-                write!(f, "{}", self.nume)
-            } else {
-                write!(f, "{}/{}", self.nume, self.deno)
+            Type::Infinity => Self::pad_non_finite(f, if f.sign_plus() { "+inf" } else { "inf" }), 
+            Type::NegInfinity => Self::pad_non_finite(f, "-inf"), 
+            Type::NaN => Self::pad_non_finite(f, "nan"), 
+            Type::Zero => f.pad_integral(true, "", "0"), 
+            Type::Normal => {
+                let abs = if self.deno == 1 {
+                    self.nume.unsigned_abs().to_string()
+                } else {
+                    format!("{}/{}", self.nume.unsigned_abs(), self.deno)
+                };
+                f.pad_integral(self.nume > 0, "", &abs)
             }
         }
     }
 }
 
+impl From<Fraction> for String {
+    /// 转换为字符串，与 `to_string` 相同
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let s: String = Fraction::new(3, 4).into();
+    /// assert_eq!(s, "3/4");
+    /// assert_eq!(String::from(Fraction::from(5)), "5");
+    /// ```
+    fn from(value: Fraction) -> Self {
+        value.to_string()
+    }
+}
+
+impl FromStr for Fraction {
+    type Err = ParseFractionError;
+
+    /// 从 `"分子/分母"` 或整数形式的字符串解析分数
+    ///
+    /// 分子分母须在 `i32` 范围内，解析结果与 [`Fraction::new`] 相同。
+    /// 同时接受 `Display` 输出的特殊值 `"inf"`、`"-inf"` 与 `"nan"`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ParseFractionError};
+    /// 
+    /// assert_eq!("3/4".parse(), Ok(Fraction::new(3, 4)));
+    /// assert_eq!(" -6/8 ".parse(), Ok(Fraction::new(-3, 4)));
+    /// assert_eq!("5".parse(), Ok(Fraction::from(5)));
+    /// assert_eq!("1/0".parse(), Ok(Fraction::INFINITY));
+    /// assert!("nan".parse::<Fraction>().unwrap().is_nan());
+    /// assert_eq!("3/x".parse::<Fraction>(), Err(ParseFractionError::InvalidInteger));
+    /// assert_eq!("1/2/3".parse::<Fraction>(), Err(ParseFractionError::InvalidInteger));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s {
+            "" => return Err(ParseFractionError::Empty), 
+            "inf" => return Ok(Self::INFINITY), 
+            "-inf" => return Ok(Self::NEG_INFINITY), 
+            "nan" => return Ok(Self::NAN), 
+            _ => {}
+        }
+
+        let (nume, deno) = s.split_once('/').unwrap_or((s, "1"));
+        let nume = nume.trim().parse::<i32>().map_err(|_| ParseFractionError::InvalidInteger)?;
+        let deno = deno.trim().parse::<i32>().map_err(|_| ParseFractionError::InvalidInteger)?;
+        Ok(Self::new(nume, deno))
+    }
+}
+
+// Fraction::eval 使用的递归下降解析器
+//   expr   := term (('+' | '-') term)*
+//   term   := factor (('*' | '/') factor)*
+//   factor := ('+' | '-') factor | '(' expr ')' | 数值
+struct ExprParser<'a> {
+    tokens: Vec<&'a str>, 
+    pos: usize, 
+}
+
+impl<'a> ExprParser<'a> {
+    fn new(expr: &'a str) -> Self {
+        let mut tokens = Vec::new();
+        let mut rest = expr.trim_start();
+        while let Some(c) = rest.chars().next() {
+            let len = if c.is_ascii_alphanumeric() {
+                rest.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(rest.len())
+            } else {
+                c.len_utf8()
+            };
+            tokens.push(&rest[..len]);
+            rest = rest[len..].trim_start();
+        }
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn expr(&mut self) -> Result<Fraction, ParseFractionError> {
+        let mut value = self.term()?;
+        while let Some(op @ ("+" | "-")) = self.peek() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            value = if op == "+" { value + rhs } else { value - rhs };
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<Fraction, ParseFractionError> {
+        let mut value = self.factor()?;
+        while let Some(op @ ("*" | "/")) = self.peek() {
+            self.pos += 1;
+            let rhs = self.factor()?;
+            value = if op == "*" { value * rhs } else { value / rhs };
+        }
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<Fraction, ParseFractionError> {
+        match self.next() {
+            Some("+") => self.factor(), 
+            Some("-") => Ok(-self.factor()?), 
+            Some("(") => {
+                let value = self.expr()?;
+                match self.next() {
+                    Some(")") => Ok(value), 
+                    _ => Err(ParseFractionError::InvalidFormat)
+                }
+            }, 
+            Some(token) if token.starts_with(|c: char| c.is_ascii_alphanumeric()) => token.parse(), 
+            _ => Err(ParseFractionError::InvalidFormat)
+        }
+    }
+}
+
 impl PartialEq for Fraction {
     /// 判断是否相等
     /// 
@@ -971,7 +5441,7 @@ impl PartialEq for Fraction {
     fn eq(&self, other: &Self) -> bool {
         match (self.frac_type, other.frac_type) {
             (Type::NaN, _) | (_, Type::NaN) => false, 
-            (_, _) => self.frac_type == self.frac_type && self.nume == other.nume && self.deno == other.deno
+            (_, _) => self.frac_type == other.frac_type && self.nume == other.nume && self.deno == other.deno
         }
     }
 }
@@ -1030,16 +5500,78 @@ impl PartialOrd for Fraction {
 
             // self_type: normal
             (Type::Normal, Type::Infinity) => Some(Ordering::Less), 
-            (Type::Normal, Type::NegInfinity) => Some(Ordering::Less), 
+            (Type::Normal, Type::NegInfinity) => Some(Ordering::Greater), 
             (Type::Normal, _) | (Type::Zero, _) => {
                 let (a, b) = (self.nume as i64, self.deno as i64);
                 let (c, d) = (other.nume as i64, other.deno as i64);
-                Some((a * d).cmp(&(b * c)))
+                // 分母恒为正，此处仍按 b * d 的符号修正，以防不变量被破坏
+                let ordering = (a * d).cmp(&(b * c));
+                Some(if (b < 0) ^ (d < 0) { ordering.reverse() } else { ordering })
             }
         }
     }
 }
 
+impl PartialEq<f64> for Fraction {
+    /// 判断分数是否与浮点数精确相等
+    ///
+    /// 浮点数按其精确的二进制有理数值参与比较，不会将分数转换为浮点数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(3, 4) == 0.75);
+    /// assert!(Fraction::new(1, 10) != 0.1);
+    /// assert!(Fraction::NAN != f64::NAN);
+    /// ```
+    fn eq(&self, other: &f64) -> bool {
+        self.cmp_f64(*other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialEq<Fraction> for f64 {
+    fn eq(&self, other: &Fraction) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<f64> for Fraction {
+    /// 与浮点数比较大小
+    ///
+    /// 将浮点数分解为精确的有理数 `mant * 2^exp` 后使用 `i128` 交叉相乘比较，
+    /// 避免将分数转换为浮点数带来的精度损失
+    ///
+    /// # 比较规则
+    /// 1. **NaN 参与比较**：任意操作数为 NaN 时返回 `None`
+    /// 2. **浮点数为无穷**：返回 `None`
+    /// 3. **分数为无穷**：`INFINITY` 大于任意有限浮点数，`NEG_INFINITY` 小于任意有限浮点数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::cmp::Ordering;
+    /// 
+    /// assert!(Fraction::new(1, 3) < 0.5);
+    /// assert!(0.5 < Fraction::new(2, 3));
+    ///
+    /// // 1.0 / 3.0 略小于 1/3
+    /// assert!(Fraction::new(1, 3) > 1.0 / 3.0);
+    ///
+    /// assert!(Fraction::INFINITY > f64::MAX);
+    /// assert_eq!(Fraction::new(1, 3).partial_cmp(&f64::INFINITY), None);
+    /// ```
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.cmp_f64(*other)
+    }
+}
+
+impl PartialOrd<Fraction> for f64 {
+    fn partial_cmp(&self, other: &Fraction) -> Option<Ordering> {
+        other.cmp_f64(*self).map(Ordering::reverse)
+    }
+}
+
 // panic! when NaN
 // impl Ord for Fraction {
 //     fn cmp(&self, other: &Self) -> Ordering {
@@ -1117,6 +5649,25 @@ impl_from_safe!(u8, u16, i8, i16);
 impl_from_unsigned_unsafe!(i32, u32, u64, u128);
 impl_from_signed_unsafe!(i64, i128);
 
+impl From<&Fraction> for Fraction {
+    /// 由引用复制，使运算符与赋值运算符可以直接接受 `&Fraction`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// 
+    /// let values = vec![Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+    /// let mut total = Fraction::ZERO;
+    /// for v in &values {
+    ///     total += v;
+    /// }
+    /// assert_eq!(total, Fraction::from(1));
+    /// ```
+    fn from(value: &Fraction) -> Self {
+        *value
+    }
+}
+
 macro_rules! impl_from_for_float {
     ($($t:ty),*) => {
         $(
@@ -1137,6 +5688,46 @@ macro_rules! impl_from_for_float {
 
 impl_from_for_float!(f32, f64);
 
+impl TryFrom<f64> for Fraction {
+    type Error = ConversionError;
+
+    /// 由 `f64` 创建最接近的分数，无法表示时说明原因
+    ///
+    /// 近似方式与运算中的 shrink 相同。失败时的错误：
+    /// - `NaNConversion`: `x` 为 `NAN`
+    /// - `InfiniteConversion`: `x` 为无穷
+    /// - `OutOfRangeError`: `x` 有限，但舍入后超出 [`MIN`](Fraction::MIN) 至 [`MAX`](Fraction::MAX) 的范围，
+    ///   或不为零而绝对值小于 [`MIN_POSITIVE`](Fraction::MIN_POSITIVE)（近似为零）
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ConversionError};
+    /// 
+    /// assert_eq!(Fraction::try_from(0.75), Ok(Fraction::new(3, 4)));
+    /// assert_eq!(Fraction::try_from(0.0), Ok(Fraction::ZERO));
+    /// assert_eq!(Fraction::try_from(f64::NAN), Err(ConversionError::NaNConversion));
+    /// assert_eq!(Fraction::try_from(f64::NEG_INFINITY), Err(ConversionError::InfiniteConversion));
+    /// assert_eq!(Fraction::try_from(1e300), Err(ConversionError::OutOfRangeError));
+    /// assert_eq!(Fraction::try_from(1e-300), Err(ConversionError::OutOfRangeError));
+    /// ```
+    fn try_from(x: f64) -> Result<Self, Self::Error> {
+        if x.is_nan() {
+            return Err(ConversionError::NaNConversion);
+        } else if x.is_infinite() {
+            return Err(ConversionError::InfiniteConversion);
+        } else if x >= Self::MAX.nume as f64 + 0.5 || x <= Self::MIN.nume as f64 - 0.5 {
+            return Err(ConversionError::OutOfRangeError);
+        }
+
+        let value = Self::approximate_f64(x);
+        if value.is_zero() && x != 0.0 {
+            Err(ConversionError::OutOfRangeError)
+        } else {
+            Ok(value)
+        }
+    }
+}
+
 macro_rules! impl_try_from_for_integer_with_lower_capacity {
     ($($t:ty),*) => {
         $(
@@ -1216,8 +5807,308 @@ impl_try_from_for_unsigned_integer_with_greater_capacity!(u32, u64, u128);
 impl_try_from_for_signed_integer_with_greater_capacity!(i32, i64, i128);
 
 impl Hash for Fraction {
+    /// 计算哈希值
+    ///
+    /// 对最简形式的 `(nume, deno, frac_type)` 计算哈希，与 `PartialEq` 保持一致：
+    /// `a == b` 时必有相同的哈希值
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::Fraction;
+    /// # use std::collections::HashSet;
+    /// let set: HashSet<Fraction> = [Fraction::new(1, 2), Fraction::new(2, 4), Fraction::INFINITY]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(set.len(), 2);
+    /// ```
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.nume.hash(state);
         self.deno.hash(state);
+        self.frac_type.hash(state);
+    }
+}
+
+/// 精确累加器，用于对大量分数求和
+///
+/// 以 `+` 依次累加时每一步都可能 shrink，误差会逐步积累。
+/// 累加器以 `i128` 精确保存中间结果，仅在 [`finish`](Self::finish) 时 shrink 一次
+///
+/// 中间结果超出 `2^95` 时（分母为大量互质数之积时可能发生），会先 shrink 为 `Fraction` 再继续累加，
+/// 此时退化为逐项累加的精度
+///
+/// # 示例
+/// ```
+/// # use fraction::{Fraction, Accumulator};
+/// 
+/// let mut acc = Accumulator::new();
+/// for n in 1..=4 {
+///     acc.push(Fraction::new(1, n));
+/// }
+/// assert_eq!(acc.finish(), Fraction::new(25, 12));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Accumulator {
+    nume: i128, 
+    deno: i128, 
+    // 累加过特殊值后，结果按 `+` 的规则保存于此
+    special: Option<Fraction>, 
+}
+
+impl Accumulator {
+    // 中间结果的上限，保证 finish 时可以直接 shrink
+    const BOUND: i128 = 1 << 95;
+
+    /// 创建值为零的累加器
+    pub fn new() -> Self {
+        Self { nume: 0, deno: 1, special: None }
+    }
+
+    /// 累加一个分数
+    ///
+    /// # 参数
+    /// - `value`: 被累加的分数，特殊值按 `+` 的规则传播
+    pub fn push(&mut self, value: Fraction) {
+        if let Some(special) = self.special {
+            self.special = Some(special + value);
+            return;
+        }
+        if !matches!(value.frac_type, Type::Normal | Type::Zero) {
+            self.special = Some(value);
+            return;
+        }
+
+        let (c, d) = (value.nume as i128, value.deno as i128);
+        match self.exact_add(c, d) {
+            Some((nume, deno)) => (self.nume, self.deno) = (nume, deno), 
+            None => {
+                let sum = Fraction::from_i128(self.nume, self.deno) + value;
+                (self.nume, self.deno) = (sum.nume as i128, sum.deno as i128);
+                if !sum.is_normal() && !sum.is_zero() {
+                    self.special = Some(sum);
+                }
+            }
+        }
+    }
+
+    /// 获取累加结果，仅在此时 shrink 一次
+    ///
+    /// # 返回值
+    /// `Fraction`，未累加任何值时为 `ZERO`
+    pub fn finish(&self) -> Fraction {
+        self.special.unwrap_or_else(|| Fraction::from_i128(self.nume, self.deno))
+    }
+
+    // 精确计算 nume/deno + c/d 并化简，超出 BOUND 时返回 None
+    fn exact_add(&self, c: i128, d: i128) -> Option<(i128, i128)> {
+        let (e, f, gcd_bd) = Fraction::lcm(self.deno, d);
+        let nume = self.nume.checked_mul(e)?.checked_add(c.checked_mul(f)?)?;
+        let deno = e.checked_mul(f)?.checked_mul(gcd_bd)?;
+
+        let gcd = Fraction::gcd(nume.abs(), deno);
+        let (nume, deno) = (nume / gcd, deno / gcd);
+        (nume.abs() < Self::BOUND && deno < Self::BOUND).then_some((nume, deno))
+    }
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 按近似值比较与哈希的分数包装，用于 `HashSet`/`HashMap` 的键
+///
+/// `Fraction` 的 `==` 比较内部表示，经过不同运算得到的近似结果即使数学上应当相等也可能不同
+/// （见 README 中 `a != b` 但 `a - b == 0` 的例子）。
+/// `ApproxKey` 在比较与哈希前先将分数近似为分母不超过 `MAX_DENOM` 的最接近的分数，
+/// 因此足够接近的分数会被视为同一个键。`Fraction` 自身的 `==` 不受影响
+///
+/// 近似规则与 [`with_denominator_cap`](Fraction::with_denominator_cap) 相同，
+/// 比较基于近似后的值，因此满足传递性且与 `Hash` 一致。
+/// 注意两个很接近的值仍可能恰好被近似到两侧不同的分数上，此时它们不相等。
+/// 所有 `NAN` 视为同一个键
+///
+/// # 参数
+/// - `MAX_DENOM`: 近似的分母上限 (正数)，默认为 `1_000_000`
+///
+/// # 示例
+/// ```
+/// # use fraction::{Fraction, ApproxKey};
+/// # use std::collections::HashSet;
+/// 
+/// let a = Fraction::new(155937625, 24970004);
+/// let b = Fraction::new(2103597937, 336845254);
+/// assert!(a != b);
+///
+/// let mut set: HashSet<ApproxKey> = HashSet::new();
+/// set.insert(ApproxKey(a));
+/// set.insert(ApproxKey(b));
+/// assert_eq!(set.len(), 1);
+///
+/// // 分母上限可以调整
+/// assert!(ApproxKey::<10>(Fraction::new(1, 3)) == ApproxKey(Fraction::new(33, 100)));
+/// ```
+// 二进制目标与库共用本模块，二进制目标中没有用到
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub struct ApproxKey<const MAX_DENOM: i32 = 1_000_000>(pub Fraction);
+
+impl<const MAX_DENOM: i32> ApproxKey<MAX_DENOM> {
+    /// 获取用于比较与哈希的近似值
+    ///
+    /// # Panics
+    /// 当 `MAX_DENOM` 不为正数时触发 panic
+    ///
+    /// # 返回值
+    /// `Fraction`
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::{Fraction, ApproxKey};
+    /// 
+    /// assert_eq!(ApproxKey::<10>(Fraction::new(333, 1000)).canonical(), Fraction::new(1, 3));
+    /// ```
+    pub fn canonical(&self) -> Fraction {
+        self.0.with_denominator_cap(MAX_DENOM)
+    }
+}
+
+impl<const MAX_DENOM: i32> From<Fraction> for ApproxKey<MAX_DENOM> {
+    fn from(value: Fraction) -> Self {
+        Self(value)
+    }
+}
+
+impl<const MAX_DENOM: i32> PartialEq for ApproxKey<MAX_DENOM> {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.canonical(), other.canonical());
+        (a.nume, a.deno) == (b.nume, b.deno)
+    }
+}
+
+impl<const MAX_DENOM: i32> Eq for ApproxKey<MAX_DENOM> {}
+
+impl<const MAX_DENOM: i32> Hash for ApproxKey<MAX_DENOM> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+/// 运算的精度策略，用于 [`add_with`](Fraction::add_with) 等方法
+///
+/// 普通运算在结果超出 `i32` 范围时 shrink 到分母不超过 `i32::MAX` 的最接近的分数。
+/// 精度策略可以为单次运算指定更小的分母上限，以精度换取更小的分母（后续运算更快、不易再次溢出）
+///
+/// # 示例
+/// ```
+/// # use fraction::{Fraction, PrecisionPolicy};
+/// 
+/// let coarse = PrecisionPolicy::new(1000);
+/// let mut x = Fraction::ZERO;
+/// for n in 1..=20 {
+///     x = x.add_with(Fraction::new(1, n), coarse);
+/// }
+/// assert!(x.denominator() <= 1000);
+/// assert_eq!(PrecisionPolicy::default().max_denom(), i32::MAX);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecisionPolicy {
+    // 结果分母的上限，由 new 保证为正数
+    max_denom: i32, 
+}
+
+impl PrecisionPolicy {
+    /// 创建精度策略
+    ///
+    /// # 参数
+    /// - `max_denom`: 结果分母的上限 (正数)
+    ///
+    /// # Panics
+    /// 当 `max_denom` 不为正数时触发 panic
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::PrecisionPolicy;
+    /// 
+    /// assert_eq!(PrecisionPolicy::new(100).max_denom(), 100);
+    /// ```
+    pub const fn new(max_denom: i32) -> Self {
+        assert!(max_denom > 0, "denominator cap must be positive");
+        Self { max_denom }
+    }
+
+    /// 结果分母的上限
+    ///
+    /// # 返回值
+    /// `i32`，总是正数
+    ///
+    /// # 示例
+    /// ```
+    /// # use fraction::PrecisionPolicy;
+    /// 
+    /// assert_eq!(PrecisionPolicy::new(100).max_denom(), 100);
+    /// ```
+    pub const fn max_denom(&self) -> i32 {
+        self.max_denom
+    }
+}
+
+impl Default for PrecisionPolicy {
+    /// 默认策略，分母上限为 `i32::MAX`，与普通运算相同
+    fn default() -> Self {
+        Self { max_denom: i32::MAX }
+    }
+}
+
+/// 用于小顶堆的分数包装
+///
+/// 按 [`total_cmp`](Fraction::total_cmp) 的逆序实现 `Ord`，因此 `BinaryHeap<MinFraction>` 优先弹出最小的分数。
+/// 与 `std::cmp::Reverse` 不同，不要求内部类型实现 `Ord`；`NAN` 视为最大的值，最后弹出，且所有 `NAN` 相等
+///
+/// # 示例
+/// ```
+/// # use fraction::{Fraction, MinFraction};
+/// # use std::collections::BinaryHeap;
+/// 
+/// let mut heap = BinaryHeap::new();
+/// heap.push(MinFraction(Fraction::new(1, 2)));
+/// heap.push(MinFraction(Fraction::NAN));
+/// heap.push(MinFraction(Fraction::new(-1, 3)));
+/// assert_eq!(heap.pop().map(Fraction::from), Some(Fraction::new(-1, 3)));
+/// assert_eq!(heap.pop().map(Fraction::from), Some(Fraction::new(1, 2)));
+/// assert!(heap.pop().map(Fraction::from).unwrap().is_nan());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MinFraction(pub Fraction);
+
+impl From<Fraction> for MinFraction {
+    fn from(value: Fraction) -> Self {
+        Self(value)
     }
-}
\ No newline at end of file
+}
+
+impl From<MinFraction> for Fraction {
+    fn from(value: MinFraction) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq for MinFraction {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for MinFraction {}
+
+impl PartialOrd for MinFraction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinFraction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0)
+    }
+}
@@ -1,3 +1,3 @@
 mod fraction;
 
-pub use fraction::{Fraction, ConversionError};
\ No newline at end of file
+pub use fraction::{Fraction, Comparison, Accumulator, ApproxKey, MinFraction, PrecisionPolicy, ConversionError, ParseFractionError};
\ No newline at end of file
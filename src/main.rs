@@ -77,10 +77,14 @@ fn main() {
 
 #[cfg(test)]
 mod tests {
-    use crate::fraction::{Fraction, ConversionError};
+    use crate::fraction::{Fraction, Comparison, Accumulator, ApproxKey, MinFraction, PrecisionPolicy, ConversionError, ParseFractionError};
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
-    use rand;
+
+    // 分子取遍 i32、分母为正的随机分数
+    fn random_fraction() -> Fraction {
+        Fraction::new(rand::random_range(i32::MIN..=i32::MAX), rand::random_range(1..=i32::MAX))
+    }
 
     pub fn sqrt_fraction(n: Fraction) -> Option<Fraction> {
         let mut prev;
@@ -149,6 +153,10 @@ mod tests {
         let a = Fraction::new(1, 2);
         let b = Fraction::new(3, 4);
         assert!(a < b);
+
+        assert!(a > Fraction::NEG_INFINITY);
+        assert!(Fraction::NEG_INFINITY < a);
+        assert!(a < Fraction::INFINITY);
     }
 
     #[test]
@@ -202,6 +210,33 @@ mod tests {
         assert_eq!(hasher1.finish(), hasher2.finish());
     }
 
+    #[test]
+    fn test_hash_matches_eq() {
+        fn hash_of(f: Fraction) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            f.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let values = [
+            Fraction::new(1, 2), Fraction::new(2, 4), Fraction::new(-1, 2), Fraction::new(3, -6), 
+            Fraction::from(7), Fraction::new(14, 2), Fraction::new(0, 5), Fraction::ZERO, 
+            Fraction::INFINITY, Fraction::new(1, 0), Fraction::from(i32::MAX), 
+            Fraction::NEG_INFINITY, Fraction::new(-3, 0), Fraction::from(i64::MIN), 
+            Fraction::NAN, Fraction::new(0, 0), Fraction::MAX, Fraction::MIN, Fraction::MIN_POSITIVE, 
+            Fraction::new(1, 3) + Fraction::new(1, 6), 
+        ];
+        for a in values {
+            for b in values {
+                if a == b {
+                    assert_eq!(hash_of(a), hash_of(b));
+                }
+            }
+        }
+        assert_ne!(hash_of(Fraction::INFINITY), hash_of(Fraction::MAX));
+        assert_ne!(hash_of(Fraction::NAN), hash_of(Fraction::ZERO));
+    }
+
     #[test]
     fn test_edge_cases() {
         let f = Fraction::new(i32::MAX, i32::MAX);
@@ -235,6 +270,2876 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_mul_add() {
+        let values = [
+            Fraction::new(1, 2), Fraction::new(-2, 3), Fraction::new(5, 7), 
+            Fraction::from(3), Fraction::new(-11, 4), Fraction::ZERO, 
+        ];
+        for &x in &values {
+            for &a in &values {
+                for &b in &values {
+                    assert_eq!(x.mul_add(a, b), x * a + b);
+                }
+            }
+        }
+
+        // exact value: 1000045999969002320/999998999167009537
+        let x = Fraction::new(1000003, 999983);
+        let c = Fraction::new(7, 1000033);
+        let exact = 1000045999969002320f64 / 999998999167009537f64;
+        let fused = x.mul_add(x, c);
+        let separate = x * x + c;
+        assert!((f64::from(fused) - exact).abs() <= (f64::from(separate) - exact).abs());
+
+        assert!(Fraction::INFINITY.mul_add(Fraction::ZERO, x).is_nan());
+        assert!(x.mul_add(Fraction::NEG_INFINITY, Fraction::INFINITY).is_nan());
+        assert!(x.mul_add(c, Fraction::NEG_INFINITY).is_neg_infinity());
+    }
+
+    #[test]
+    fn test_from_parts() {
+        assert_eq!(Fraction::from_parts(1, 3, 4), Fraction::new(3, 4));
+        assert_eq!(Fraction::from_parts(-1, 3, 4), Fraction::new(-3, 4));
+        assert_eq!(Fraction::from_parts(i8::MIN, 6, 8), Fraction::new(-3, 4));
+        assert!(Fraction::from_parts(0, 3, 4).is_zero());
+        assert!(Fraction::from_parts(1, 0, 4).is_zero());
+        assert!(Fraction::from_parts(0, 3, 0).is_nan());
+        assert!(Fraction::from_parts(1, 0, 0).is_nan());
+        assert!(Fraction::from_parts(1, 3, 0).is_infinity());
+        assert!(Fraction::from_parts(-1, 3, 0).is_neg_infinity());
+
+        // i32::MIN magnitude
+        assert_eq!(Fraction::from_parts(-1, 1 << 31, 2), Fraction::from(-(1 << 30)));
+
+        // numerator above i32::MAX that has to be shrunk
+        assert_eq!(Fraction::from_parts(1, u32::MAX, 3), Fraction::from(1431655765));
+        let f = Fraction::from_parts(-1, u32::MAX, 7);
+        assert!(f.is_negative());
+        assert_eq!(f, Fraction::new(-1227133513, 2));
+        assert!((f64::from(f) + u32::MAX as f64 / 7.0).abs() < 0.5);
+        assert!(Fraction::from_parts(1, u32::MAX, 1).is_infinity());
+    }
+
+    #[test]
+    fn test_compare_with_f64() {
+        assert!(Fraction::new(1, 4) < 0.5);
+        assert!(Fraction::new(3, 4) > 0.5);
+        assert!(Fraction::new(-3, 4) < -0.5);
+        assert!(Fraction::new(1, 2) == 0.5);
+        assert!(0.5 == Fraction::new(1, 2));
+        assert!(0.25 < Fraction::new(1, 2));
+        assert!(Fraction::ZERO == 0.0);
+        assert!(Fraction::ZERO == -0.0);
+        assert!(Fraction::ZERO > -f64::MIN_POSITIVE);
+        assert!(Fraction::MIN_POSITIVE > f64::MIN_POSITIVE);
+        assert!(Fraction::MAX < 1e10);
+        assert!(Fraction::MIN > -1e10);
+
+        // extremely close but not equal
+        let third = 1.0 / 3.0;
+        assert!(f64::from(Fraction::new(1, 3)) == third);
+        assert!(Fraction::new(1, 3) != third);
+        assert!(Fraction::new(1, 3) > third);
+        assert!(third < Fraction::new(1, 3));
+        assert!(Fraction::new(-1, 3) < -third);
+        assert!(Fraction::new(1, 10) < 0.1);
+
+        assert_eq!(Fraction::new(1, 3).partial_cmp(&f64::NAN), None);
+        assert_eq!(Fraction::new(1, 3).partial_cmp(&f64::NEG_INFINITY), None);
+        assert_eq!(Fraction::NAN.partial_cmp(&0.5), None);
+        assert_eq!(f64::INFINITY.partial_cmp(&Fraction::INFINITY), None);
+        assert!(Fraction::NEG_INFINITY < f64::MIN);
+    }
+
+    #[test]
+    fn test_with_denominator_cap() {
+        fn assert_closest(value: Fraction, cap: i32) {
+            let capped = value.with_denominator_cap(cap);
+            let target = f64::from(value);
+            let candidates: Vec<Fraction> = (1..=cap)
+                .flat_map(|q| {
+                    let p = (target * q as f64).floor() as i32;
+                    [Fraction::new(p, q), Fraction::new(p + 1, q)]
+                })
+                .collect();
+            assert!(candidates.contains(&capped));
+            let best = (f64::from(capped) - target).abs();
+            for c in candidates {
+                assert!(best <= (f64::from(c) - target).abs());
+            }
+        }
+
+        assert_eq!(Fraction::new(355, 113).with_denominator_cap(10), Fraction::new(22, 7));
+        assert_eq!(Fraction::new(-355, 113).with_denominator_cap(10), Fraction::new(-22, 7));
+        assert_eq!(Fraction::new(355, 113).with_denominator_cap(1), Fraction::from(3));
+        assert_eq!(Fraction::new(1, 1000).with_denominator_cap(10), Fraction::ZERO);
+        for cap in 1..=20 {
+            assert_closest(Fraction::new(355, 113), cap);
+            assert_closest(Fraction::new(-1000, 2047), cap);
+        }
+        assert!(Fraction::NAN.with_denominator_cap(3).is_nan());
+        assert!(Fraction::ZERO.with_denominator_cap(3).is_zero());
+    }
+
+    #[test]
+    fn test_logarithms() {
+        assert_eq!(Fraction::from(1).ln(), Some(Fraction::ZERO));
+        assert_eq!(Fraction::from(1).log2(), Some(Fraction::ZERO));
+        assert_eq!(Fraction::from(1).log10(), Some(Fraction::ZERO));
+
+        let inputs = [
+            Fraction::new(1, 2), Fraction::new(2, 3), Fraction::from(2), Fraction::from(10), 
+            Fraction::new(355, 113), Fraction::MIN_POSITIVE, Fraction::MAX, 
+        ];
+        for x in inputs {
+            let v = f64::from(x);
+            assert!((f64::from(x.ln().unwrap()) - v.ln()).abs() < 1e-6);
+            assert!((f64::from(x.log2().unwrap()) - v.log2()).abs() < 1e-6);
+            assert!((f64::from(x.log10().unwrap()) - v.log10()).abs() < 1e-6);
+        }
+
+        // 接近 1 时不经过 f64，结果与高精度计算得到的最接近的分数相同
+        assert_eq!(Fraction::new(1000000001, 1000000000).ln(), Some(Fraction::new(2, 2000000001)));
+        assert_eq!(Fraction::new(2147483647, 2147483646).ln(), Some(Fraction::new(1, 2147483647)));
+        assert_eq!(Fraction::new(2147483646, 2147483647).ln(), Some(Fraction::new(-1, 2147483647)));
+        assert_eq!(Fraction::new(2147483647, 2147483646).log2(), Some(Fraction::new(1, 1488522235)));
+        // 2 的幂的 log2 精确
+        assert_eq!(Fraction::new(1, 1 << 30).log2(), Some(Fraction::from(-30)));
+        assert_eq!(Fraction::from(1 << 30).log2(), Some(Fraction::from(30)));
+        assert_eq!(Fraction::from(100000).log10(), Some(Fraction::from(5)));
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(1..i32::MAX), rand::random_range(1..i32::MAX));
+            let v = f64::from(x);
+            let (ln, log2) = (x.ln().unwrap(), x.log2().unwrap());
+            assert!((f64::from(ln) - v.ln()).abs() < 1e-15 + f64::from(ln).powi(2).max(1.0) / i32::MAX as f64);
+            assert!((f64::from(log2) - v.log2()).abs() < 1e-15 + f64::from(log2).powi(2).max(1.0) / i32::MAX as f64);
+        }
+
+        for x in [Fraction::ZERO, Fraction::from(-3), Fraction::NAN, Fraction::NEG_INFINITY] {
+            assert_eq!(x.ln(), None);
+            assert_eq!(x.log2(), None);
+            assert_eq!(x.log10(), None);
+        }
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(Fraction::ZERO.exp(), Fraction::from(1));
+
+        // relative tolerance for large results (small denominators only),
+        // absolute tolerance near zero (denominator bounded by i32::MAX)
+        let inputs = [
+            Fraction::new(1, 2), Fraction::new(-2, 3), Fraction::from(1), Fraction::from(-5), 
+            Fraction::new(355, 113), Fraction::from(21), Fraction::from(-21), 
+        ];
+        for x in inputs {
+            let expected = f64::from(x).exp();
+            assert!((f64::from(x.exp()) - expected).abs() <= (expected * 1e-9).max(1e-9));
+        }
+
+        // best approximations with numerator and denominator at most i32::MAX
+        assert_eq!(Fraction::new(1, i32::MAX).exp(), Fraction::new(i32::MAX, i32::MAX - 1));
+        assert_eq!(Fraction::new(-1, i32::MAX).exp(), Fraction::new(i32::MAX - 1, i32::MAX));
+        assert_eq!(Fraction::new(355, 113).exp(), Fraction::new(204400057, 8832925));
+        assert_eq!(Fraction::from(10).exp(), Fraction::new(1749936628, 79447));
+        assert_eq!(Fraction::from(21).exp(), Fraction::from(1318815734));
+        assert_eq!(Fraction::from(-21).exp(), Fraction::new(1, 1318815734));
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-2148..=2148), rand::random_range(100..=1000));
+            let expected = f64::from(x).exp();
+            let bound = 1e-15 * expected.max(1.0) + (expected.max(1.0).powi(2) / i32::MAX as f64).min(0.5);
+            assert!((f64::from(x.exp()) - expected).abs() <= bound);
+        }
+
+        assert!(Fraction::new(2149, 100).exp().is_infinity());
+        assert!(Fraction::new(2148, 100).exp().is_normal());
+        assert!(Fraction::new(-2149, 100).exp().is_zero());
+        assert!(Fraction::new(-2148, 100).exp().is_normal());
+        assert!(Fraction::INFINITY.exp().is_infinity());
+        assert!(Fraction::NEG_INFINITY.exp().is_zero());
+        assert!(Fraction::NAN.exp().is_nan());
+    }
+
+    #[test]
+    fn test_negate() {
+        let values = [
+            Fraction::new(2, 3), Fraction::new(-2, 3), Fraction::MAX, Fraction::MIN, 
+            Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, 
+        ];
+        for x in values {
+            let mut negated = x;
+            negated.negate();
+            let expected = -x;
+            assert_eq!(negated, expected);
+            assert_eq!(-&x, expected);
+        }
+
+        let mut nan = Fraction::NAN;
+        nan.negate();
+        assert!(nan.is_nan());
+        assert!((-&Fraction::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_new_matches_reduction() {
+        fn gcd(a: i64, b: i64) -> i64 {
+            if b == 0 { a.abs() } else { gcd(b, a % b) }
+        }
+
+        for _ in 0..5000 {
+            let n = rand::random_range(-100000..=100000);
+            let d = rand::random_range(-100000..=100000);
+            if n == 0 || d == 0 {
+                continue;
+            }
+            let g = gcd(n as i64, d as i64);
+            let sign = if (n < 0) ^ (d < 0) { "-" } else { "" };
+            let (a, b) = ((n as i64).abs() / g, (d as i64).abs() / g);
+            let expected = if b == 1 { format!("{}{}", sign, a) } else { format!("{}{}/{}", sign, a, b) };
+
+            let f = Fraction::new(n, d);
+            assert_eq!(f.to_string(), expected);
+            assert_eq!(f, Fraction::new(n / g as i32, d / g as i32));
+        }
+
+        // inputs that still need shrink
+        assert_eq!(Fraction::new(i32::MIN, 3), Fraction::new(-1431655765, 2));
+        assert!(Fraction::new(i32::MIN, 2).is_normal());
+        assert_eq!(Fraction::new(i32::MIN, i32::MIN + 1), Fraction::new(i32::MAX, i32::MAX - 1));
+    }
+
+    #[test]
+    fn test_checked_pow_and_reciprocal() {
+        assert_eq!(Fraction::new(2, 3).checked_pow(2), Some(Fraction::new(4, 9)));
+        assert_eq!(Fraction::new(2, 3).checked_pow(1), Some(Fraction::new(2, 3)));
+        assert_eq!(Fraction::new(2, 3).checked_pow(0), Some(Fraction::from(1)));
+        assert_eq!(Fraction::new(-2, 3).checked_pow(3), Some(Fraction::new(-8, 27)));
+        assert_eq!(Fraction::new(-2, 3).checked_pow(-2), Some(Fraction::new(9, 4)));
+        assert_eq!(Fraction::from(46340).checked_pow(2), Some(Fraction::from(2147395600)));
+        assert_eq!(Fraction::from(46341).checked_pow(2), None);
+        assert_eq!(Fraction::new(1, 65536).checked_pow(2), None);
+        // (-2)^31 == i32::MIN collides with NEG_INFINITY
+        assert_eq!(Fraction::from(-2).checked_pow(31), None);
+        assert_eq!(Fraction::from(-2).checked_pow(30), Some(Fraction::from(1 << 30)));
+        assert_eq!(Fraction::from(1).checked_pow(i32::MIN), Some(Fraction::from(1)));
+
+        assert_eq!(Fraction::ZERO.checked_pow(3), Some(Fraction::ZERO));
+        assert_eq!(Fraction::ZERO.checked_pow(0), Some(Fraction::from(1)));
+        assert_eq!(Fraction::ZERO.checked_pow(-3), None);
+        assert_eq!(Fraction::INFINITY.checked_pow(2), None);
+        assert_eq!(Fraction::NAN.checked_pow(0), None);
+
+        assert_eq!(Fraction::new(-2, 3).checked_reciprocal(), Some(Fraction::new(-3, 2)));
+        assert_eq!(Fraction::MAX.checked_reciprocal(), Some(Fraction::new(1, i32::MAX - 1)));
+        assert_eq!(Fraction::new(1, i32::MAX).checked_reciprocal(), None);
+        assert_eq!(Fraction::new(-1, i32::MAX).checked_reciprocal(), Some(Fraction::MIN));
+        assert_eq!(Fraction::NEG_INFINITY.checked_reciprocal(), Some(Fraction::ZERO));
+        assert_eq!(Fraction::ZERO.checked_reciprocal(), None);
+        assert_eq!(Fraction::NAN.checked_reciprocal(), None);
+    }
+
+    #[test]
+    fn test_sorted_and_median() {
+        let odd = [Fraction::new(2, 3), Fraction::new(-1, 2), Fraction::new(1, 3), Fraction::from(5), Fraction::ZERO];
+        assert_eq!(
+            Fraction::sorted(&odd), 
+            [Fraction::new(-1, 2), Fraction::ZERO, Fraction::new(1, 3), Fraction::new(2, 3), Fraction::from(5)]
+        );
+        assert_eq!(Fraction::median(&odd), Fraction::new(1, 3));
+
+        let even = [Fraction::new(2, 3), Fraction::new(-1, 2), Fraction::new(1, 3), Fraction::from(5)];
+        assert_eq!(Fraction::median(&even), Fraction::new(1, 2));
+        assert_eq!(Fraction::median(&even), Fraction::new(1, 3).midpoint(Fraction::new(2, 3)));
+        assert_eq!(Fraction::median(&[Fraction::new(7, 3)]), Fraction::new(7, 3));
+        assert!(Fraction::median(&[]).is_nan());
+
+        // NaN sorts last
+        let with_nan = [Fraction::from(3), Fraction::NAN, Fraction::INFINITY, Fraction::from(1), Fraction::NEG_INFINITY];
+        let sorted = Fraction::sorted(&with_nan);
+        assert!(sorted[0].is_neg_infinity());
+        assert_eq!(sorted[1..3], [Fraction::from(1), Fraction::from(3)]);
+        assert!(sorted[3].is_infinity());
+        assert!(sorted[4].is_nan());
+        assert_eq!(Fraction::median(&with_nan), Fraction::from(3));
+        assert!(Fraction::median(&[Fraction::from(1), Fraction::NAN]).is_nan());
+
+        // midpoint is exact before the final shrink
+        assert_eq!(Fraction::MAX.midpoint(Fraction::MIN), Fraction::new(-1, 2));
+        assert_eq!(Fraction::MAX.midpoint(Fraction::from(1)), Fraction::new(i32::MAX, 2));
+        assert_eq!(Fraction::MAX.midpoint(-Fraction::MAX), Fraction::ZERO);
+        assert!(Fraction::INFINITY.midpoint(Fraction::from(1)).is_infinity());
+    }
+
+    #[test]
+    fn test_mixed_string() {
+        let cases = [
+            (Fraction::new(7, 3), "2 1/3"), 
+            (Fraction::new(-7, 3), "-2 1/3"), 
+            (Fraction::new(1, 3), "1/3"), 
+            (Fraction::new(-1, 3), "-1/3"), 
+            (Fraction::from(12), "12"), 
+            (Fraction::from(-12), "-12"), 
+            (Fraction::ZERO, "0"), 
+            (Fraction::new(i32::MAX, 2), "1073741823 1/2"), 
+            (Fraction::new(-i32::MAX, 2), "-1073741823 1/2"), 
+        ];
+        for (value, text) in cases {
+            assert_eq!(value.to_mixed_string(), text);
+            assert_eq!(Fraction::from_mixed_string(text), Ok(value));
+        }
+
+        assert_eq!(Fraction::from_mixed_string("  +2   1/3 "), Ok(Fraction::new(7, 3)));
+        assert_eq!(Fraction::from_mixed_string("-0 1/3"), Ok(Fraction::new(-1, 3)));
+        assert_eq!(Fraction::from_mixed_string("2 1/0"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::from_mixed_string("2 1"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::from_mixed_string("2 1/-3"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::from_mixed_string("a 1/3"), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::from_mixed_string("   "), Err(ParseFractionError::Empty));
+
+        assert_eq!("-3/-4".parse(), Ok(Fraction::new(3, 4)));
+        assert_eq!("3 / 4".parse(), Ok(Fraction::new(3, 4)));
+        assert_eq!("2147483648".parse::<Fraction>(), Err(ParseFractionError::InvalidInteger));
+        assert!("-inf".parse::<Fraction>().unwrap().is_neg_infinity());
+    }
+
+    #[test]
+    fn test_const_reciprocal_and_signum() {
+        const TINY: Fraction = Fraction::MAX.reciprocal_const();
+        const NEG_TINY: Fraction = Fraction::MIN.reciprocal_const();
+        const ZERO_RECIPROCAL: Fraction = Fraction::ZERO.reciprocal_const();
+        const INF_RECIPROCAL: Fraction = Fraction::NEG_INFINITY.reciprocal_const();
+        const ONE: Fraction = Fraction::MIN_POSITIVE.signum_const();
+        const NEG_ONE: Fraction = Fraction::NEG_INFINITY.signum_const();
+        const ZERO_SIGN: Fraction = Fraction::ZERO.signum_const();
+
+        assert_eq!(TINY, Fraction::MAX.reciprocal());
+        assert_eq!(NEG_TINY, Fraction::MIN.reciprocal());
+        assert_eq!(ZERO_RECIPROCAL, Fraction::ZERO.reciprocal());
+        assert_eq!(INF_RECIPROCAL, Fraction::NEG_INFINITY.reciprocal());
+        assert_eq!(ONE, Fraction::from(1));
+        assert_eq!(NEG_ONE, Fraction::NEG_INFINITY.sign());
+        assert_eq!(ZERO_SIGN, Fraction::ZERO.sign());
+
+        for x in [Fraction::new(-7, 3), Fraction::new(2, 9), Fraction::from(5)] {
+            assert_eq!(x.reciprocal_const(), x.reciprocal());
+            assert_eq!(x.signum_const(), x.sign());
+        }
+        assert!(Fraction::NAN.reciprocal_const().is_nan());
+        assert!(Fraction::NAN.signum_const().is_nan());
+    }
+
+    #[test]
+    fn test_positive_denominator() {
+        let negative = Fraction::new(-3, 4);
+        let values = [
+            Fraction::new(3, -4), 
+            Fraction::new(-3, -4), 
+            Fraction::new(i32::MAX, i32::MIN), 
+            Fraction::new(i32::MIN, i32::MIN + 1), 
+            Fraction::new(0, -5), 
+            Fraction::from_parts(-1, 3, 4), 
+            Fraction::from_mixed_string("-2 1/3").unwrap(), 
+            "3/-4".parse().unwrap(), 
+            negative.reciprocal(), 
+            negative.reciprocal_const(), 
+            negative.checked_reciprocal().unwrap(), 
+            negative.checked_pow(-3).unwrap(), 
+            negative.sign(), 
+            negative.abs(), 
+            -negative, 
+            negative + Fraction::new(1, -7), 
+            negative - Fraction::new(1, -7), 
+            negative * Fraction::new(1, -7), 
+            negative / Fraction::new(1, -7), 
+            negative / -7, 
+            negative.mul_add(negative, negative), 
+            negative.midpoint(Fraction::new(1, -7)), 
+            negative.with_denominator_cap(3), 
+            Fraction::from(-5i64), 
+        ];
+        for value in values {
+            assert!(value.denominator() > 0);
+        }
+
+        assert_eq!(Fraction::new(3, -4).numerator(), -3);
+        assert_eq!(Fraction::new(3, -4).denominator(), 4);
+        assert!(Fraction::new(3, -4) < Fraction::new(-1, 2));
+        assert!(Fraction::new(1, -2) < Fraction::ZERO);
+    }
+
+    #[test]
+    fn test_egyptian_fraction() {
+        let terms = Fraction::new(4, 13).to_egyptian();
+        assert_eq!(terms, [Fraction::new(1, 4), Fraction::new(1, 18), Fraction::new(1, 468)]);
+
+        for value in [Fraction::new(4, 13), Fraction::new(99, 100), Fraction::new(2, 3), Fraction::new(1, 7)] {
+            let terms = value.to_egyptian();
+            assert!(!terms.is_empty());
+            assert!(terms.iter().all(|t| t.numerator() == 1));
+            assert!(terms.windows(2).all(|w| w[0].denominator() < w[1].denominator()));
+            let sum = terms.iter().fold(Fraction::ZERO, |acc, &t| acc + t);
+            assert_eq!(sum, value);
+        }
+
+        // 5/121 = 1/25 + 1/757 + 1/763309 + 1/873960180913 + ..., which overflows i32
+        assert!(Fraction::new(5, 121).to_egyptian().is_empty());
+        assert!(Fraction::from(1).to_egyptian().is_empty());
+        assert!(Fraction::ZERO.to_egyptian().is_empty());
+        assert!(Fraction::NAN.to_egyptian().is_empty());
+        assert!(Fraction::INFINITY.to_egyptian().is_empty());
+    }
+
+    #[test]
+    fn test_convergents() {
+        let values = [
+            Fraction::new(103993, 33102), Fraction::new(-1000, 2047), Fraction::new(i32::MAX - 2, i32::MAX - 1), 
+            Fraction::new(1, 7), Fraction::from(5), Fraction::MIN, Fraction::ZERO, 
+        ];
+        for x in values {
+            let (n, d) = (x.numerator() as i128, x.denominator() as i128);
+            // |p/q - n/d| = err / (q * d)
+            let errors: Vec<(i128, i128)> = x.convergents()
+                .map(|c| {
+                    let (p, q) = (c.numerator() as i128, c.denominator() as i128);
+                    ((p * d - n * q).abs(), q * d)
+                })
+                .collect();
+            assert!(!errors.is_empty());
+            for w in errors.windows(2) {
+                let ((e_0, s_0), (e_1, s_1)) = (w[0], w[1]);
+                assert!(e_1 * s_0 < e_0 * s_1);
+            }
+            assert_eq!(x.convergents().last(), Some(x));
+        }
+
+        assert_eq!(Fraction::INFINITY.convergents().count(), 0);
+        assert_eq!(Fraction::NEG_INFINITY.convergents().count(), 0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let values = [
+            Fraction::new(1, 3), Fraction::new(-7, 5), Fraction::MAX, Fraction::MIN, 
+            Fraction::MIN_POSITIVE, Fraction::new(i32::MAX - 2, i32::MAX - 1), Fraction::ZERO, 
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(a.lerp(b, Fraction::ZERO), a);
+                assert_eq!(a.lerp(b, Fraction::from(1)), b);
+                assert_eq!(a.lerp(b, Fraction::new(1, 2)), a.midpoint(b));
+            }
+        }
+
+        assert_eq!(Fraction::ZERO.lerp(Fraction::from(10), Fraction::new(3, 10)), Fraction::from(3));
+        assert_eq!(Fraction::ZERO.lerp(Fraction::from(10), Fraction::from(-1)), Fraction::from(-10));
+        assert!(Fraction::NAN.lerp(Fraction::from(1), Fraction::ZERO).is_nan());
+        assert!(Fraction::from(1).lerp(Fraction::NEG_INFINITY, Fraction::new(1, 2)).is_neg_infinity());
+        assert!(Fraction::from(1).lerp(Fraction::from(2), Fraction::INFINITY).is_infinity());
+    }
+
+    #[test]
+    fn test_cmp_exact() {
+        use std::cmp::Ordering;
+
+        let a = Fraction::new(i32::MAX - 1, i32::MAX);
+        let b = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        assert_eq!(a.cmp_exact(&b), Some(Ordering::Greater));
+        assert_eq!(b.cmp_exact(&a), Some(Ordering::Less));
+        assert_eq!((-a).cmp_exact(&-b), Some(Ordering::Less));
+        assert_eq!(a.cmp_exact(&a), Some(Ordering::Equal));
+
+        assert_eq!(Fraction::MAX.cmp_exact(&Fraction::MIN), Some(Ordering::Greater));
+        assert_eq!(Fraction::MIN_POSITIVE.cmp_exact(&Fraction::ZERO), Some(Ordering::Greater));
+        assert_eq!(Fraction::MIN_POSITIVE.cmp_exact(&Fraction::new(1, i32::MAX - 1)), Some(Ordering::Less));
+        assert_eq!(Fraction::MAX.cmp_exact(&Fraction::INFINITY), Some(Ordering::Less));
+        assert_eq!(Fraction::MIN.cmp_exact(&Fraction::NEG_INFINITY), Some(Ordering::Greater));
+        assert_eq!(Fraction::INFINITY.cmp_exact(&Fraction::INFINITY), Some(Ordering::Equal));
+        assert_eq!(Fraction::NAN.cmp_exact(&Fraction::NAN), None);
+
+        for _ in 0..1000 {
+            let x = random_fraction();
+            let y = random_fraction();
+            assert_eq!(x.cmp_exact(&y), x.partial_cmp(&y));
+        }
+    }
+
+    #[test]
+    fn test_from_continued_fraction() {
+        let pi = Fraction::new(103993, 33102);
+        let terms = [3, 7, 15, 1, 292];
+        assert_eq!(Fraction::from_continued_fraction(&terms), pi);
+        assert_eq!(Fraction::from_continued_fraction(&[0]), Fraction::ZERO);
+        assert_eq!(Fraction::from_continued_fraction(&[5]), Fraction::from(5));
+
+        let sqrt2 = 2f64.sqrt();
+        let mut last_error = f64::INFINITY;
+        for expansions in 1..=12 {
+            let error = (f64::from(Fraction::from_periodic_cf(&[1], &[2], expansions)) - sqrt2).abs();
+            assert!(error < last_error);
+            last_error = error;
+        }
+        let approx = Fraction::from_periodic_cf(&[1], &[2], 1000);
+        assert!((f64::from(approx) - sqrt2).abs() < 1e-15);
+
+        // 黄金比例 = [1; 1, 1, ...]，收敛到相邻斐波那契数之比
+        assert_eq!(Fraction::from_periodic_cf(&[1], &[1], 10), Fraction::new(144, 89));
+        assert!(Fraction::from_periodic_cf(&[], &[], 10).is_nan());
+    }
+
+    #[test]
+    fn test_same_shrunk() {
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert!(a.same_shrunk(b));
+        assert!(b.same_shrunk(a));
+        assert!((-a).same_shrunk(-b));
+        assert!(!a.same_shrunk(-b));
+
+        assert!(!Fraction::MAX.same_shrunk(Fraction::new(i32::MAX - 2, 1)));
+        assert!(!Fraction::MIN_POSITIVE.same_shrunk(Fraction::ZERO));
+        // 差约为 2^-62，小于 MIN_POSITIVE
+        assert!(Fraction::new(i32::MAX - 1, i32::MAX).same_shrunk(Fraction::new(i32::MAX - 2, i32::MAX - 1)));
+        assert!(Fraction::ZERO.same_shrunk(Fraction::ZERO));
+        assert!(!Fraction::INFINITY.same_shrunk(Fraction::MAX));
+        assert!(!Fraction::INFINITY.same_shrunk(Fraction::NEG_INFINITY));
+
+        for _ in 0..1000 {
+            let x = random_fraction();
+            let y = random_fraction();
+            assert!(x.same_shrunk(x));
+            assert_eq!(x.same_shrunk(y), x - y == Fraction::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_means() {
+        let values = [Fraction::from(1), Fraction::from(2), Fraction::from(4)];
+        assert_eq!(Fraction::harmonic_mean(&values), Fraction::new(12, 7));
+        assert_eq!(Fraction::geometric_mean(&values), Some(Fraction::from(2)));
+
+        let values = [Fraction::new(4, 9), Fraction::new(9, 4)];
+        assert_eq!(Fraction::geometric_mean(&values), Some(Fraction::from(1)));
+        assert_eq!(Fraction::geometric_mean(&[Fraction::MAX]), Some(Fraction::MAX));
+        let big = Fraction::from(46340);
+        assert_eq!(Fraction::geometric_mean(&[big; 8]), Some(big));
+
+        assert_eq!(Fraction::geometric_mean(&[]), None);
+        assert_eq!(Fraction::geometric_mean(&[Fraction::from(2), Fraction::ZERO]), None);
+        assert_eq!(Fraction::geometric_mean(&[Fraction::from(2), Fraction::INFINITY]), None);
+        assert_eq!(Fraction::geometric_mean(&[Fraction::from(2), Fraction::from(3)]), None);
+        assert_eq!(Fraction::geometric_mean(&[Fraction::MAX; 5]), None);
+
+        assert!(Fraction::harmonic_mean(&[Fraction::from(1), Fraction::ZERO]).is_zero());
+        assert!(Fraction::harmonic_mean(&[Fraction::NAN]).is_nan());
+        // 倒数和恰为零
+        assert!(Fraction::harmonic_mean(&[Fraction::from(1), Fraction::from(-1)]).is_nan());
+        assert!(Fraction::harmonic_mean(&[Fraction::new(1, 3), Fraction::new(-1, 2), Fraction::from(-1)]).is_nan());
+        assert_eq!(Fraction::harmonic_mean(&[Fraction::from(2), Fraction::from(-2), Fraction::from(1)]), Fraction::from(3));
+        // 商精确计算，不经过 shrink 后的倒数和
+        assert_eq!(Fraction::harmonic_mean(&[Fraction::MAX; 3]), Fraction::MAX);
+        assert_eq!(Fraction::harmonic_mean(&[Fraction::new(1, 1 << 30); 4]), Fraction::new(1, 1 << 30));
+        assert_eq!(Fraction::harmonic_mean(&[Fraction::new(1, 3); 3]), Fraction::new(1, 3));
+
+        let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(values.iter().sum::<Fraction>(), Fraction::from(1));
+        assert_eq!(values.iter().product::<Fraction>(), Fraction::new(1, 36));
+        assert!(std::iter::empty::<Fraction>().sum::<Fraction>().is_zero());
+    }
+
+    #[test]
+    fn test_scale_pow10() {
+        let quarter = Fraction::new(1, 4);
+        assert_eq!(quarter.scale_pow10(2), Fraction::from(25));
+        assert_eq!(quarter.scale_pow10(-1), Fraction::new(1, 40));
+        assert_eq!(quarter.scale_pow10(0), quarter);
+        assert_eq!(Fraction::new(-3, 7).scale_pow10(3), Fraction::new(-3000, 7));
+        assert_eq!(Fraction::new(1, 1000).scale_pow10(3), Fraction::from(1));
+        assert_eq!(Fraction::from(1).scale_pow10(9), Fraction::from(1_000_000_000));
+        assert_eq!(Fraction::from(1).scale_pow10(-9), Fraction::new(1, 1_000_000_000));
+        assert_eq!(Fraction::new(1, 1 << 30).scale_pow10(28), Fraction::new(1, 1 << 30) * 10i32.pow(9) * 10i32.pow(9) * 10i32.pow(9) * 10);
+
+        assert!(Fraction::from(1).scale_pow10(10).is_infinity());
+        assert_eq!(Fraction::from(-1).scale_pow10(10), Fraction::from(-100000) * 100000);
+        assert_eq!(Fraction::from(-1).scale_pow10(100), Fraction::MIN);
+        assert!(Fraction::MIN_POSITIVE.scale_pow10(28).is_infinity());
+        assert!(Fraction::MAX.scale_pow10(-28).is_zero());
+        assert!(quarter.scale_pow10(i32::MAX).is_infinity());
+        assert!(quarter.scale_pow10(i32::MIN).is_zero());
+        assert!(Fraction::new(-1, 3).scale_pow10(i32::MIN).is_zero());
+
+        // 结果接近 i32 边界时与直接运算的 shrink 结果一致
+        let x = Fraction::new(123456789, 1000);
+        assert_eq!(x.scale_pow10(1), x * 10);
+        assert_eq!(Fraction::new(7, 3).scale_pow10(-10), Fraction::new(7, 3) / 100000 / 100000);
+
+        assert!(Fraction::NAN.scale_pow10(2).is_nan());
+        assert!(Fraction::INFINITY.scale_pow10(-2).is_infinity());
+        assert!(Fraction::ZERO.scale_pow10(100).is_zero());
+    }
+
+    #[test]
+    fn test_pack() {
+        let specials = [
+            Fraction::MAX, Fraction::MIN, Fraction::MIN_POSITIVE, -Fraction::MIN_POSITIVE, 
+            Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, 
+        ];
+        for f in specials {
+            let g = Fraction::unpack(f.pack());
+            assert_eq!((g.numerator(), g.denominator()), (f.numerator(), f.denominator()));
+            assert_eq!(g, f);
+        }
+        assert!(Fraction::unpack(Fraction::NAN.pack()).is_nan());
+        assert!(Fraction::unpack(Fraction::INFINITY.pack()).is_infinity());
+        assert!(Fraction::unpack(Fraction::NEG_INFINITY.pack()).is_neg_infinity());
+        assert!(Fraction::unpack(Fraction::MAX.pack()).is_normal());
+        assert!(Fraction::unpack(Fraction::MIN.pack()).is_normal());
+
+        let packed: std::collections::HashSet<u64> = specials.iter().chain([&Fraction::NAN]).map(Fraction::pack).collect();
+        assert_eq!(packed.len(), specials.len() + 1);
+
+        for _ in 0..1000 {
+            let f = random_fraction();
+            let g = Fraction::unpack(f.pack());
+            assert_eq!((g.numerator(), g.denominator()), (f.numerator(), f.denominator()));
+        }
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        assert_eq!(Fraction::new(1, 2).checked_sub(Fraction::new(1, 3)), Some(Fraction::new(1, 6)));
+        assert_eq!(Fraction::new(1, 3).checked_sub(Fraction::new(1, 2)), Some(Fraction::new(-1, 6)));
+        assert_eq!(Fraction::new(2, 3).checked_sub(Fraction::new(2, 3)), Some(Fraction::ZERO));
+        assert_eq!(Fraction::from(5).checked_sub(Fraction::ZERO), Some(Fraction::from(5)));
+        assert_eq!(Fraction::new(1, 46340).checked_sub(Fraction::new(1, 46341)), Some(Fraction::new(1, 46340 * 46341)));
+
+        let a = Fraction::new(i32::MAX - 1, i32::MAX);
+        let b = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        assert!((a - b).is_zero());
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(Fraction::new(1, 65536).checked_sub(Fraction::new(1, 65537)), None);
+
+        // 差与 INFINITY 的内部表示冲突，或超出范围
+        assert_eq!(Fraction::MAX.checked_sub(Fraction::from(-1)), None);
+        assert_eq!(Fraction::MIN.checked_sub(Fraction::from(1)), None);
+        assert_eq!(Fraction::MIN.checked_sub(Fraction::ZERO), Some(Fraction::MIN));
+
+        assert_eq!(Fraction::INFINITY.checked_sub(Fraction::from(1)), None);
+        assert_eq!(Fraction::from(1).checked_sub(Fraction::NAN), None);
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-1000..=1000), rand::random_range(1..=1000));
+            let y = Fraction::new(rand::random_range(-1000..=1000), rand::random_range(1..=1000));
+            assert_eq!(x.checked_sub(y), Some(x - y));
+        }
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let a = Fraction::new(3, 4);
+        assert_eq!(format!("{:>8}", a), "     3/4");
+        assert_eq!(format!("{:8}", a), "     3/4");
+        assert_eq!(format!("{:<8}|", a), "3/4     |");
+        assert_eq!(format!("{:^7}", a), "  3/4  ");
+        assert_eq!(format!("{:_>6}", a), "___3/4");
+        assert_eq!(format!("{:#^9}", Fraction::new(-3, 4)), "##-3/4###");
+        assert_eq!(format!("{:>5}", Fraction::from(-2)), "   -2");
+        assert_eq!(format!("{:2}", a), "3/4");
+
+        assert_eq!(format!("{:>6}", Fraction::INFINITY), "   inf");
+        assert_eq!(format!("{:<6}|", Fraction::NEG_INFINITY), "-inf  |");
+        assert_eq!(format!("{:^5}", Fraction::NAN), " nan ");
+        assert_eq!(format!("{:>3}", Fraction::ZERO), "  0");
+    }
+
+    #[test]
+    fn test_try_from_i32() {
+        assert_eq!(Fraction::try_from_i32(i32::MAX), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from_i32(i32::MIN), Err(ConversionError::OutOfRangeError));
+
+        assert_eq!(Fraction::try_from_i32(i32::MAX - 1), Ok(Fraction::MAX));
+        assert_eq!(Fraction::try_from_i32(i32::MIN + 1), Ok(Fraction::MIN));
+        assert_eq!(Fraction::try_from_i32(-7), Ok(Fraction::from(-7)));
+        assert!(Fraction::try_from_i32(0).unwrap().is_zero());
+        assert!(Fraction::try_from_i32(i32::MAX - 1).unwrap().is_normal());
+    }
+
+    #[test]
+    fn test_cumulative_sum() {
+        let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        let sums: Vec<Fraction> = Fraction::cumulative_sum(values.into_iter()).collect();
+        assert_eq!(sums, [Fraction::new(1, 2), Fraction::new(5, 6), Fraction::from(1)]);
+
+        assert_eq!(Fraction::cumulative_sum(std::iter::empty()).count(), 0);
+
+        let values = [Fraction::from(1), Fraction::INFINITY, Fraction::from(1), Fraction::NEG_INFINITY];
+        let sums: Vec<Fraction> = Fraction::cumulative_sum(values.into_iter()).collect();
+        assert_eq!(sums[0], Fraction::from(1));
+        assert!(sums[1].is_infinity());
+        assert!(sums[2].is_infinity());
+        assert!(sums[3].is_nan());
+
+        let last = Fraction::cumulative_sum((1..=10).map(|n| Fraction::new(1, n))).last();
+        assert_eq!(last, Some(Fraction::new(7381, 2520)));
+    }
+
+    #[test]
+    fn test_closest_of() {
+        let candidates = [Fraction::new(1, 4), Fraction::new(1, 3), Fraction::new(1, 2)];
+        assert_eq!(Fraction::new(26, 100).closest_of(&candidates), Some(Fraction::new(1, 4)));
+        assert_eq!(Fraction::from(5).closest_of(&candidates), Some(Fraction::new(1, 2)));
+        assert_eq!(Fraction::from(-5).closest_of(&candidates), Some(Fraction::new(1, 4)));
+        assert_eq!(Fraction::new(1, 3).closest_of(&candidates), Some(Fraction::new(1, 3)));
+
+        // 距离相同时取分母较小者
+        let candidates = [Fraction::new(3, 4), Fraction::new(1, 4), Fraction::new(1, 2)];
+        assert_eq!(Fraction::new(3, 8).closest_of(&candidates), Some(Fraction::new(1, 2)));
+        assert_eq!(Fraction::new(5, 8).closest_of(&candidates), Some(Fraction::new(1, 2)));
+
+        // 精确比较，不受 shrink 影响
+        let a = Fraction::new(i32::MAX - 1, i32::MAX);
+        let b = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        assert_eq!(a.closest_of(&[b, a]), Some(a));
+        assert_eq!(b.closest_of(&[a, b]), Some(b));
+
+        let candidates = [Fraction::NAN, Fraction::INFINITY, Fraction::from(7)];
+        assert_eq!(Fraction::from(1000).closest_of(&candidates), Some(Fraction::from(7)));
+        assert_eq!(Fraction::from(1).closest_of(&[Fraction::NAN]), None);
+        assert_eq!(Fraction::NAN.closest_of(&candidates), None);
+        assert_eq!(Fraction::INFINITY.closest_of(&candidates), None);
+        assert_eq!(Fraction::from(1).closest_of(&[]), None);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(Fraction::binomial(5, 2), Fraction::from(10));
+        assert_eq!(Fraction::binomial(5, 3), Fraction::from(10));
+        assert_eq!(Fraction::binomial(0, 0), Fraction::from(1));
+        assert_eq!(Fraction::binomial(7, 0), Fraction::from(1));
+        assert_eq!(Fraction::binomial(7, 7), Fraction::from(1));
+        assert_eq!(Fraction::binomial(u32::MAX, 1), Fraction::INFINITY);
+        assert_eq!(Fraction::binomial(33, 16), Fraction::from(1166803110));
+        assert!(Fraction::binomial(34, 17).is_infinity());
+        assert!(Fraction::binomial(100, 50).is_infinity());
+        assert!(Fraction::binomial(3, 4).is_zero());
+
+        // 帕斯卡恒等式
+        for n in 1..30 {
+            for k in 1..n {
+                assert_eq!(Fraction::binomial(n, k), Fraction::binomial(n - 1, k - 1) + Fraction::binomial(n - 1, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_float() {
+        let values = [
+            Fraction::new(1, 3), Fraction::new(-22, 7), Fraction::MAX, Fraction::MIN_POSITIVE, 
+            Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, 
+        ];
+        for x in values {
+            assert_eq!(x.to_f64(), f64::from(x));
+            assert_eq!(x.to_f32(), f32::from(x));
+        }
+        assert!(Fraction::NAN.to_f64().is_nan());
+        assert!(Fraction::NAN.to_f32().is_nan());
+        assert_eq!(Fraction::INFINITY.to_f64(), f64::INFINITY);
+        assert_eq!(Fraction::NEG_INFINITY.to_f32(), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_eval() {
+        // 优先级
+        assert_eq!(Fraction::eval("1/2 + 1/3 * 3/4"), Ok(Fraction::new(3, 4)));
+        assert_eq!(Fraction::eval("1 + 2 * 3"), Ok(Fraction::from(7)));
+        assert_eq!(Fraction::eval("1 - 2 - 3"), Ok(Fraction::from(-4)));
+        assert_eq!(Fraction::eval("12 / 3 / 2"), Ok(Fraction::from(2)));
+
+        // 括号
+        assert_eq!(Fraction::eval("(1 + 2) * 3"), Ok(Fraction::from(9)));
+        assert_eq!(Fraction::eval("1 / (2 / 3)"), Ok(Fraction::new(3, 2)));
+        assert_eq!(Fraction::eval("((1/2))"), Ok(Fraction::new(1, 2)));
+
+        // 负数与特殊值
+        assert_eq!(Fraction::eval("-1/2"), Ok(Fraction::new(-1, 2)));
+        assert_eq!(Fraction::eval("2 * -3"), Ok(Fraction::from(-6)));
+        assert_eq!(Fraction::eval("-(1 + 1)"), Ok(Fraction::from(-2)));
+        assert_eq!(Fraction::eval(" 7 "), Ok(Fraction::from(7)));
+        assert!(Fraction::eval("0 / 0").unwrap().is_nan());
+        assert!(Fraction::eval("-1 / 0").unwrap().is_neg_infinity());
+        assert!(Fraction::eval("inf - inf").unwrap().is_nan());
+
+        assert_eq!(Fraction::eval(""), Err(ParseFractionError::Empty));
+        assert_eq!(Fraction::eval("   "), Err(ParseFractionError::Empty));
+        assert_eq!(Fraction::eval("1 +"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::eval("(1"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::eval("1)"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::eval("1 2"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::eval("1 % 2"), Err(ParseFractionError::InvalidFormat));
+        assert_eq!(Fraction::eval("3000000000"), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::eval("x"), Err(ParseFractionError::InvalidInteger));
+    }
+
+    #[test]
+    fn test_accumulator() {
+        // H_20 的分母在 i32 范围内，两种方式都是精确的
+        let mut acc = Accumulator::new();
+        let mut naive = Fraction::ZERO;
+        for n in 1..=20 {
+            acc.push(Fraction::new(1, n));
+            naive += Fraction::new(1, n);
+        }
+        assert_eq!(acc.finish(), Fraction::new(55835135, 15519504));
+        assert_eq!(naive, acc.finish());
+
+        // H_40 = N / D 的分母超出 i32 范围，逐项累加会多次 shrink
+        fn gcd(a: u128, b: u128) -> u128 {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+        let (mut big_n, mut big_d) = (0u128, 1u128);
+        let mut acc = Accumulator::default();
+        let mut naive = Fraction::ZERO;
+        for n in 1..=40u128 {
+            let g = gcd(big_d, n);
+            (big_n, big_d) = (big_n * (n / g) + big_d / g, big_d * (n / g));
+            acc.push(Fraction::new(1, n as i32));
+            naive += Fraction::new(1, n as i32);
+        }
+        let g = gcd(big_n, big_d);
+        let (big_n, big_d) = ((big_n / g) as i128, (big_d / g) as i128);
+        // |p/q - N/D| 的分子 |pD - Nq|，再除以 q 比较
+        let error = |x: Fraction| (
+            (x.numerator() as i128 * big_d - big_n * x.denominator() as i128).abs(), 
+            x.denominator() as i128
+        );
+        let ((e_acc, q_acc), (e_naive, q_naive)) = (error(acc.finish()), error(naive));
+        assert!(e_acc * q_naive < e_naive * q_acc);
+
+        // 特殊值
+        let mut acc = Accumulator::new();
+        assert!(acc.finish().is_zero());
+        acc.push(Fraction::from(1));
+        acc.push(Fraction::INFINITY);
+        assert!(acc.finish().is_infinity());
+        acc.push(Fraction::from(-1));
+        assert!(acc.finish().is_infinity());
+        acc.push(Fraction::NEG_INFINITY);
+        assert!(acc.finish().is_nan());
+
+        // 中间结果超出 i32 范围也不影响最终结果
+        let mut acc = Accumulator::new();
+        acc.push(Fraction::MAX);
+        acc.push(Fraction::MAX);
+        acc.push(Fraction::MIN);
+        assert_eq!(acc.finish(), Fraction::from(i32::MAX - 2));
+        assert!((Fraction::MAX + Fraction::MAX + Fraction::MIN).is_infinity());
+    }
+
+    #[test]
+    fn test_saturating_from() {
+        assert_eq!(Fraction::saturating_from(123_456i64), Fraction::from(123_456));
+        assert_eq!(Fraction::saturating_from(-7i8), Fraction::from(-7));
+        assert_eq!(Fraction::saturating_from(i32::MAX as i64 - 1), Fraction::MAX);
+        assert_eq!(Fraction::saturating_from(i32::MIN as i64 + 1), Fraction::MIN);
+        assert!(Fraction::saturating_from(0u32).is_zero());
+
+        assert_eq!(Fraction::saturating_from(i32::MAX), Fraction::MAX);
+        assert_eq!(Fraction::saturating_from(i64::MAX), Fraction::MAX);
+        assert_eq!(Fraction::saturating_from(u64::MAX), Fraction::MAX);
+        assert_eq!(Fraction::saturating_from(i128::MAX), Fraction::MAX);
+        assert!(Fraction::saturating_from(u32::MAX).is_normal());
+
+        assert_eq!(Fraction::saturating_from(i32::MIN), Fraction::MIN);
+        assert_eq!(Fraction::saturating_from(i64::MIN), Fraction::MIN);
+        assert_eq!(Fraction::saturating_from(i128::MIN), Fraction::MIN);
+        assert!(Fraction::saturating_from(i64::MIN).is_normal());
+    }
+
+    #[test]
+    fn test_display_sign_plus() {
+        assert_eq!(format!("{:+}", Fraction::new(3, 4)), "+3/4");
+        assert_eq!(format!("{:+}", Fraction::from(2)), "+2");
+        assert_eq!(format!("{:+}", Fraction::new(-3, 4)), "-3/4");
+        assert_eq!(format!("{:+}", Fraction::from(-2)), "-2");
+        assert_eq!(format!("{:+}", Fraction::ZERO), "+0");
+        assert_eq!(format!("{:+}", Fraction::INFINITY), "+inf");
+        assert_eq!(format!("{:+}", Fraction::NEG_INFINITY), "-inf");
+        assert_eq!(format!("{:+}", Fraction::NAN), "nan");
+
+        // 与宽度、对齐组合
+        assert_eq!(format!("{:+7}", Fraction::new(3, 4)), "   +3/4");
+        assert_eq!(format!("{:<+7}|", Fraction::new(3, 4)), "+3/4   |");
+        assert_eq!(format!("{:+07}", Fraction::new(3, 4)), "+0003/4");
+        assert_eq!(format!("{:+07}", Fraction::INFINITY), "   +inf");
+        assert_eq!(format!("{:+6}", Fraction::NAN), "   nan");
+        assert_eq!(format!("{:_^+8}", Fraction::INFINITY), "__+inf__");
+    }
+
+    #[test]
+    fn test_from_f64_exact() {
+        assert_eq!(Fraction::from_f64_exact(0.5), Some(Fraction::new(1, 2)));
+        assert_eq!(Fraction::from_f64_exact(0.25), Some(Fraction::new(1, 4)));
+        assert_eq!(Fraction::from_f64_exact(0.75), Some(Fraction::new(3, 4)));
+        assert_eq!(Fraction::from_f64_exact(-2.5), Some(Fraction::new(-5, 2)));
+        assert_eq!(Fraction::from_f64_exact(1024.0), Some(Fraction::from(1024)));
+        assert_eq!(Fraction::from_f64_exact(0.0), Some(Fraction::ZERO));
+        assert_eq!(Fraction::from_f64_exact(-0.0), Some(Fraction::ZERO));
+
+        assert_eq!(Fraction::from_f64_exact(0.1), None);
+        assert_eq!(Fraction::from_f64_exact(0.3), None);
+        assert_eq!(Fraction::from_f64_exact(1.0 / 3.0), None);
+
+        // 边界
+        assert_eq!(Fraction::from_f64_exact((i32::MAX - 1) as f64), Some(Fraction::MAX));
+        assert_eq!(Fraction::from_f64_exact((i32::MIN + 1) as f64), Some(Fraction::MIN));
+        assert_eq!(Fraction::from_f64_exact(i32::MAX as f64), None);
+        assert_eq!(Fraction::from_f64_exact(i32::MIN as f64), None);
+        assert_eq!(Fraction::from_f64_exact(2f64.powi(-30)), Some(Fraction::new(1, 1 << 30)));
+        assert_eq!(Fraction::from_f64_exact(2f64.powi(-31)), None);
+        assert_eq!(Fraction::from_f64_exact(1e300), None);
+        assert_eq!(Fraction::from_f64_exact(f64::MIN_POSITIVE), None);
+        assert_eq!(Fraction::from_f64_exact(f64::NAN), None);
+        assert_eq!(Fraction::from_f64_exact(f64::NEG_INFINITY), None);
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-(1 << 20)..=(1 << 20)), 1 << rand::random_range(0..=10));
+            assert_eq!(Fraction::from_f64_exact(f64::from(x)), Some(x));
+        }
+    }
+
+    #[test]
+    fn test_dist_to_nearest_int() {
+        assert_eq!(Fraction::new(7, 3).dist_to_nearest_int(), Fraction::new(1, 3));
+        assert_eq!(Fraction::new(8, 3).dist_to_nearest_int(), Fraction::new(1, 3));
+        assert_eq!(Fraction::new(-8, 3).dist_to_nearest_int(), Fraction::new(1, 3));
+        assert_eq!(Fraction::new(5, 2).dist_to_nearest_int(), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(-1, 2).dist_to_nearest_int(), Fraction::new(1, 2));
+        assert_eq!(Fraction::MIN_POSITIVE.dist_to_nearest_int(), Fraction::MIN_POSITIVE);
+        assert_eq!(Fraction::new(i32::MAX - 1, i32::MAX).dist_to_nearest_int(), Fraction::MIN_POSITIVE);
+
+        assert!(Fraction::from(3).dist_to_nearest_int().is_zero());
+        assert!(Fraction::MAX.dist_to_nearest_int().is_zero());
+        assert!(Fraction::MIN.dist_to_nearest_int().is_zero());
+        assert!(Fraction::ZERO.dist_to_nearest_int().is_zero());
+
+        assert!(Fraction::INFINITY.dist_to_nearest_int().is_nan());
+        assert!(Fraction::NEG_INFINITY.dist_to_nearest_int().is_nan());
+        assert!(Fraction::NAN.dist_to_nearest_int().is_nan());
+
+        for _ in 0..1000 {
+            let x = random_fraction();
+            let d = x.dist_to_nearest_int();
+            assert!(d >= Fraction::ZERO && d <= Fraction::new(1, 2));
+        }
+    }
+
+    #[test]
+    fn test_scaled_int() {
+        assert_eq!(Fraction::new(1, 4).to_scaled_int(2), Some(25));
+        assert_eq!(Fraction::from_scaled_int(25, 2), Fraction::new(1, 4));
+        assert_eq!(Fraction::from(12).to_scaled_int(2), Some(1200));
+        assert_eq!(Fraction::ZERO.to_scaled_int(50), Some(0));
+
+        // 两位小数往返
+        for cents in -1000..=1000 {
+            assert_eq!(Fraction::from_scaled_int(cents, 2).to_scaled_int(2), Some(cents));
+        }
+
+        // 银行家舍入
+        assert_eq!(Fraction::new(1, 8).to_scaled_int(2), Some(12));
+        assert_eq!(Fraction::new(3, 8).to_scaled_int(2), Some(38));
+        assert_eq!(Fraction::new(-1, 8).to_scaled_int(2), Some(-12));
+        assert_eq!(Fraction::new(5, 2).to_scaled_int(0), Some(2));
+        assert_eq!(Fraction::new(7, 2).to_scaled_int(0), Some(4));
+        assert_eq!(Fraction::new(1, 3).to_scaled_int(4), Some(3333));
+        assert_eq!(Fraction::new(-2, 3).to_scaled_int(4), Some(-6667));
+
+        // 范围
+        assert_eq!(Fraction::MAX.to_scaled_int(9), Some((i32::MAX as i64 - 1) * 1_000_000_000));
+        assert_eq!(Fraction::MAX.to_scaled_int(10), None);
+        assert_eq!(Fraction::MIN_POSITIVE.to_scaled_int(28), Some(4656612875245796924));
+        assert_eq!(Fraction::MIN_POSITIVE.to_scaled_int(29), None);
+        assert_eq!(Fraction::MIN_POSITIVE.to_scaled_int(9), Some(0));
+        assert_eq!(Fraction::INFINITY.to_scaled_int(2), None);
+        assert_eq!(Fraction::NAN.to_scaled_int(2), None);
+
+        assert_eq!(Fraction::from_scaled_int(i64::MAX, 0), Fraction::INFINITY);
+        assert_eq!(Fraction::from_scaled_int(1_000_000_000_000, 10), Fraction::from(100));
+        assert!(Fraction::from_scaled_int(i64::MAX, 29).is_zero());
+        assert!(Fraction::from_scaled_int(0, 3).is_zero());
+    }
+
+    #[test]
+    fn test_dyadic() {
+        assert!(Fraction::new(3, 8).is_dyadic());
+        assert!(Fraction::new(-1, 1 << 30).is_dyadic());
+        assert!(Fraction::from(7).is_dyadic());
+        assert!(Fraction::ZERO.is_dyadic());
+        assert!(!Fraction::new(1, 3).is_dyadic());
+        assert!(Fraction::new(3, 12).is_dyadic());
+        assert!(!Fraction::MIN_POSITIVE.is_dyadic());
+        assert!(!Fraction::INFINITY.is_dyadic());
+        assert!(!Fraction::NEG_INFINITY.is_dyadic());
+        assert!(!Fraction::NAN.is_dyadic());
+
+        assert_eq!(Fraction::new(1, 3).nearest_dyadic(4), Fraction::new(5, 16));
+        assert_eq!(Fraction::new(-1, 3).nearest_dyadic(4), Fraction::new(-5, 16));
+        assert_eq!(Fraction::new(1, 3).nearest_dyadic(1), Fraction::new(1, 2));
+        assert_eq!(Fraction::new(1, 5).nearest_dyadic(1), Fraction::ZERO);
+        // 恰在中间时取偶数
+        assert_eq!(Fraction::new(3, 32).nearest_dyadic(4), Fraction::new(1, 8));
+        assert_eq!(Fraction::new(5, 32).nearest_dyadic(4), Fraction::new(1, 8));
+        assert_eq!(Fraction::new(3, 8).nearest_dyadic(100), Fraction::new(3, 8));
+        assert_eq!(Fraction::MAX.nearest_dyadic(30), Fraction::MAX);
+        assert_eq!(Fraction::MIN.nearest_dyadic(30), Fraction::MIN);
+        // 分子超出范围时自动减少位数
+        assert_eq!(Fraction::new(700, 3).nearest_dyadic(30), Fraction::new(1957341867, 1 << 23));
+
+        for bits in 0..=30 {
+            let x = Fraction::new(rand::random_range(-1000..=1000), rand::random_range(1..=1000));
+            let d = x.nearest_dyadic(bits);
+            assert!(d.is_dyadic());
+            if bits <= 20 {
+                assert!((d - x).abs() <= Fraction::new(1, 1 << bits) / 2);
+            }
+        }
+
+        assert!(Fraction::NAN.nearest_dyadic(4).is_nan());
+        assert!(Fraction::INFINITY.nearest_dyadic(4).is_infinity());
+    }
+
+    #[test]
+    fn test_split_floor() {
+        assert_eq!(Fraction::new(7, 3).split_floor(), (2, Fraction::new(1, 3)));
+        assert_eq!(Fraction::new(-7, 3).split_floor(), (-3, Fraction::new(2, 3)));
+        assert_eq!(Fraction::new(1, 2).split_floor(), (0, Fraction::new(1, 2)));
+        assert_eq!(Fraction::new(-1, 2).split_floor(), (-1, Fraction::new(1, 2)));
+        assert_eq!(Fraction::from(5).split_floor(), (5, Fraction::ZERO));
+        assert_eq!(Fraction::MAX.split_floor(), (i32::MAX - 1, Fraction::ZERO));
+        assert_eq!(Fraction::MIN.split_floor(), (i32::MIN + 1, Fraction::ZERO));
+        assert_eq!(Fraction::new(-1, i32::MAX).split_floor(), (-1, Fraction::new(i32::MAX - 1, i32::MAX)));
+        assert_eq!(Fraction::ZERO.split_floor(), (0, Fraction::ZERO));
+
+        let (floor, rem) = Fraction::INFINITY.split_floor();
+        assert_eq!(floor, 0);
+        assert!(rem.is_nan());
+        assert!(Fraction::NAN.split_floor().1.is_nan());
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-100000..=100000), rand::random_range(1..=100000));
+            let (floor, rem) = x.split_floor();
+            assert!(rem >= Fraction::ZERO && rem < Fraction::from(1));
+            assert_eq!(Fraction::from(floor) + rem, x);
+        }
+    }
+
+    #[test]
+    fn test_new_checked() {
+        assert_eq!(Fraction::new_checked(6, 8), Ok(Fraction::new(3, 4)));
+        assert_eq!(Fraction::new_checked(-6, -8), Ok(Fraction::new(3, 4)));
+        assert_eq!(Fraction::new_checked(0, -5), Ok(Fraction::ZERO));
+        assert_eq!(Fraction::new_checked(i32::MAX, 2), Ok(Fraction::new(i32::MAX, 2)));
+        assert_eq!(Fraction::new_checked(i32::MAX - 1, 1), Ok(Fraction::MAX));
+        assert_eq!(Fraction::new_checked(-i32::MAX, 1), Ok(Fraction::MIN));
+        assert_eq!(Fraction::new_checked(i32::MAX, -1), Ok(Fraction::MIN));
+        assert_eq!(Fraction::new_checked(1, i32::MAX), Ok(Fraction::MIN_POSITIVE));
+        assert_eq!(Fraction::new_checked(i32::MIN, i32::MIN), Ok(Fraction::from(1)));
+        assert_eq!(Fraction::new_checked(i32::MIN, 2), Ok(Fraction::from(-(1 << 30))));
+
+        assert_eq!(Fraction::new_checked(5, 0), Err(ConversionError::InfiniteConversion));
+        assert_eq!(Fraction::new_checked(-5, 0), Err(ConversionError::InfiniteConversion));
+        assert_eq!(Fraction::new_checked(0, 0), Err(ConversionError::NaNConversion));
+        assert_eq!(Fraction::new_checked(i32::MAX, 1), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::new_checked(i32::MIN, 1), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::new_checked(i32::MIN, -1), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::new_checked(i32::MIN, 3), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::new_checked(1, i32::MIN), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::new_checked(-i32::MAX, -1), Err(ConversionError::OutOfRangeError));
+    }
+
+    #[test]
+    fn test_enumerate_range() {
+        let values = Fraction::enumerate_range(Fraction::ZERO, Fraction::from(1), 4);
+        assert_eq!(values, [
+            Fraction::ZERO, Fraction::new(1, 4), Fraction::new(1, 3), Fraction::new(1, 2), 
+            Fraction::new(2, 3), Fraction::new(3, 4), Fraction::from(1), 
+        ]);
+
+        let values = Fraction::enumerate_range(Fraction::new(-1, 2), Fraction::new(1, 3), 3);
+        assert_eq!(values, [
+            Fraction::new(-1, 2), Fraction::new(-1, 3), Fraction::ZERO, Fraction::new(1, 3), 
+        ]);
+
+        // 端点不必在结果中
+        let values = Fraction::enumerate_range(Fraction::new(2, 7), Fraction::new(3, 7), 3);
+        assert_eq!(values, [Fraction::new(1, 3)]);
+        assert!(Fraction::enumerate_range(Fraction::new(1, 5), Fraction::new(1, 4), 3).is_empty());
+        assert_eq!(Fraction::enumerate_range(Fraction::from(2), Fraction::from(2), 5), [Fraction::from(2)]);
+
+        // Farey 序列 F_n 的长度
+        let farey = Fraction::enumerate_range(Fraction::ZERO, Fraction::from(1), 8);
+        assert_eq!(farey.len(), 23);
+        assert!(farey.windows(2).all(|w| w[0] < w[1]));
+
+        // 分子不超出范围，且不包含 INFINITY
+        let values = Fraction::enumerate_range(Fraction::new(i32::MAX - 3, 1), Fraction::MAX, 1);
+        assert_eq!(values, [Fraction::from(i32::MAX - 3), Fraction::from(i32::MAX - 2), Fraction::MAX]);
+
+        assert!(Fraction::enumerate_range(Fraction::from(1), Fraction::ZERO, 4).is_empty());
+        assert!(Fraction::enumerate_range(Fraction::ZERO, Fraction::INFINITY, 4).is_empty());
+        assert!(Fraction::enumerate_range(Fraction::NAN, Fraction::ZERO, 4).is_empty());
+    }
+
+    #[test]
+    fn test_recip() {
+        let values = [
+            Fraction::new(2, 3), Fraction::new(-2, 3), Fraction::from(7), Fraction::MAX, Fraction::MIN, 
+            Fraction::MIN_POSITIVE, -Fraction::MIN_POSITIVE, 
+            Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, 
+        ];
+        for x in values {
+            assert_eq!(x.recip(), x.reciprocal());
+            assert_eq!(x.recip(), Fraction::from(1) / x);
+        }
+        assert!(Fraction::NAN.recip().is_nan());
+
+        // 倒数与 INFINITY 的内部表示冲突
+        assert!(Fraction::MIN_POSITIVE.reciprocal().is_infinity());
+        assert_eq!(Fraction::MIN_POSITIVE.reciprocal(), Fraction::INFINITY);
+        assert_eq!((-Fraction::MIN_POSITIVE).reciprocal(), Fraction::MIN);
+        assert!((-Fraction::MIN_POSITIVE).reciprocal().is_normal());
+        assert_eq!(Fraction::MIN.reciprocal(), -Fraction::MIN_POSITIVE);
+    }
+
+    #[test]
+    fn test_apportion() {
+        let shares = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(Fraction::apportion(&shares, 10), Some(vec![5, 3, 2]));
+        assert_eq!(Fraction::apportion(&shares, 6), Some(vec![3, 2, 1]));
+        assert_eq!(Fraction::apportion(&shares, 1), Some(vec![1, 0, 0]));
+        assert_eq!(Fraction::apportion(&shares, 0), Some(vec![0, 0, 0]));
+
+        // 份额不必归一化，相同余数时靠前者优先
+        assert_eq!(Fraction::apportion(&[Fraction::from(1); 3], 10), Some(vec![4, 3, 3]));
+        assert_eq!(Fraction::apportion(&[Fraction::from(2), Fraction::ZERO, Fraction::from(2)], 5), Some(vec![3, 0, 2]));
+        assert_eq!(Fraction::apportion(&[Fraction::new(1, 7)], i32::MAX), Some(vec![i32::MAX]));
+
+        // 不合法的输入返回 None
+        assert_eq!(Fraction::apportion(&shares, -1), None);
+        assert_eq!(Fraction::apportion(&[Fraction::from(1), Fraction::from(-1)], 10), None);
+        assert_eq!(Fraction::apportion(&[Fraction::from(1), Fraction::NAN], 10), None);
+        assert_eq!(Fraction::apportion(&[Fraction::from(1), Fraction::INFINITY], 10), None);
+        assert_eq!(Fraction::apportion(&[Fraction::ZERO; 2], 10), None);
+        assert_eq!(Fraction::apportion(&[], 10), None);
+        assert_eq!(Fraction::apportion(&[Fraction::MAX; 2], 10), None);
+
+        let check = |shares: &[Fraction], total: i32| {
+            let seats = Fraction::apportion(shares, total).unwrap();
+            assert_eq!(seats.iter().map(|&n| n as i64).sum::<i64>(), total as i64);
+
+            // 每一份与配额之差小于 1
+            let sum: f64 = shares.iter().map(|&x| f64::from(x)).sum();
+            for (share, &seat) in shares.iter().zip(&seats) {
+                let quota = f64::from(*share) / sum * total as f64;
+                assert!((quota - seat as f64).abs() < 1.0 + 1e-6, "{} {} {:?}", quota, seat, shares);
+            }
+        };
+        for _ in 0..100 {
+            let shares: Vec<Fraction> = (0..rand::random_range(1..10))
+                .map(|_| Fraction::new(rand::random_range(1..100), rand::random_range(1..100)))
+                .collect();
+            check(&shares, rand::random_range(0..10000));
+        }
+
+        // 分母为两两互质的大素数时公倍数超出 i128，份额之和经过 shrink
+        let primes = [2147483647, 2147483629, 2147483587, 2147483579, 2147483563];
+        let shares: Vec<Fraction> = primes.iter().map(|&p| Fraction::new(p / 2, p)).collect();
+        check(&shares, 10);
+        check(&shares, i32::MAX);
+        for _ in 0..100 {
+            let shares: Vec<Fraction> = primes.iter()
+                .map(|&p| Fraction::new(rand::random_range(1..p), p))
+                .collect();
+            check(&shares, rand::random_range(0..=i32::MAX));
+        }
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        assert_eq!(Fraction::ZERO.sin(), Fraction::ZERO);
+        assert_eq!(Fraction::ZERO.cos(), Fraction::from(1));
+
+        let args = [
+            Fraction::new(1, 2), Fraction::new(-1, 3), Fraction::from(1), Fraction::new(355, 113), 
+            Fraction::new(22, 7), Fraction::from(-100), Fraction::new(1, 1000), Fraction::MAX, Fraction::MIN, 
+        ];
+        // 结果接近分母较小的分数时，shrink 的误差上限为 1 / i32::MAX
+        let tolerance = 1.0 / i32::MAX as f64;
+        for x in args {
+            let v = f64::from(x);
+            assert!((f64::from(x.sin()) - v.sin()).abs() < tolerance, "sin({})", x);
+            assert!((f64::from(x.cos()) - v.cos()).abs() < tolerance, "cos({})", x);
+        }
+        // cos(355/113) 与 -1 相差约 3.6e-14，-1 是 i32 范围内最接近的分数
+        assert_eq!(Fraction::new(355, 113).cos(), Fraction::from(-1));
+        assert!((f64::from(Fraction::new(1, 2).sin()) - 0.5f64.sin()).abs() < 1e-15);
+
+        // 与高精度计算得到的 i32 范围内最接近的分数相同，包括需要大幅缩减范围的参数
+        assert_eq!(Fraction::from(1).sin(), Fraction::new(887252051, 1054405995));
+        assert_eq!(Fraction::new(1, 3).cos(), Fraction::new(1161055912, 1228686573));
+        assert_eq!(Fraction::MAX.sin(), Fraction::new(309567983, 1646971078));
+        assert_eq!(Fraction::MAX.cos(), Fraction::new(-1815053285, 1847991327));
+
+        // sin² + cos² ≈ 1
+        for _ in 0..100 {
+            let x = Fraction::new(rand::random_range(-10000..=10000), rand::random_range(1..=1000));
+            let (s, c) = (x.sin(), x.cos());
+            let one = s.mul_add(s, c * c);
+            assert!((f64::from(one) - 1.0).abs() < 4.0 * tolerance);
+        }
+
+        assert!(Fraction::INFINITY.sin().is_nan());
+        assert!(Fraction::NEG_INFINITY.cos().is_nan());
+        assert!(Fraction::NAN.sin().is_nan());
+    }
+
+    #[test]
+    fn test_from_ratio_u64() {
+        // 可以约分到范围内
+        assert_eq!(Fraction::from_ratio_u64(6_000_000_000, 4_000_000_000, 1), Fraction::new(3, 2));
+        assert_eq!(Fraction::from_ratio_u64(6_000_000_000, 4_000_000_000, -1), Fraction::new(-3, 2));
+        assert_eq!(Fraction::from_ratio_u64(u64::MAX, u64::MAX, 1), Fraction::from(1));
+        assert_eq!(Fraction::from_ratio_u64(1 << 40, 1 << 41, 1), Fraction::new(1, 2));
+
+        // 无法约分，使用 shrink 近似
+        let x = Fraction::from_ratio_u64(10_000_000_019, 3_000_000_001, 1);
+        assert!(x.denominator() < i32::MAX && x.numerator() < i32::MAX);
+        assert!((f64::from(x) - 10_000_000_019f64 / 3_000_000_001f64).abs() < 1e-15);
+        assert_eq!(Fraction::from_ratio_u64(1, u64::MAX, 1), Fraction::ZERO);
+        assert_eq!(Fraction::from_ratio_u64(u64::MAX, 3, 1), Fraction::INFINITY);
+        assert_eq!(Fraction::from_ratio_u64(u64::MAX, 3, -1), Fraction::MIN);
+
+        assert!(Fraction::from_ratio_u64(5, 7, 0).is_zero());
+        assert!(Fraction::from_ratio_u64(0, 0, 1).is_nan());
+        assert!(Fraction::from_ratio_u64(5, 0, 1).is_infinity());
+    }
+
+    #[test]
+    fn test_shrink_with_error() {
+        assert_eq!(Fraction::shrink_with_error(6_000_000_000, 4_000_000_000), (Fraction::new(3, 2), 0.0));
+        assert_eq!(Fraction::shrink_with_error(0, 5), (Fraction::ZERO, 0.0));
+
+        let (nume, deno) = (10_000_000_019u64, 3_000_000_001u64);
+        let (approx, error) = Fraction::shrink_with_error(nume, deno);
+        assert_eq!(approx, Fraction::from_ratio_u64(nume, deno, 1));
+        let (p, q) = (approx.numerator() as i128, approx.denominator() as i128);
+        let exact_error = p * deno as i128 - nume as i128 * q;
+        assert!(exact_error != 0);
+        assert_eq!(error, exact_error as f64 / (q * deno as i128) as f64);
+        assert_eq!(error.signum(), exact_error.signum() as f64);
+
+        // 小于 MIN_POSITIVE 的值 shrink 为零，误差为 -精确值
+        let (approx, error) = Fraction::shrink_with_error(1, u64::MAX);
+        assert!(approx.is_zero());
+        assert_eq!(error, -1.0 / u64::MAX as f64);
+
+        let (approx, error) = Fraction::shrink_with_error(u64::MAX, 1);
+        assert!(approx.is_infinity());
+        assert_eq!(error, f64::INFINITY);
+        assert!(Fraction::shrink_with_error(1, 0).1.is_nan());
+    }
+
+    #[test]
+    fn test_saturating_int_conversions() {
+        // 向零取整
+        assert_eq!(Fraction::new(7, 2).to_i32_saturating(), 3);
+        assert_eq!(Fraction::new(-7, 2).to_i32_saturating(), -3);
+        assert_eq!(Fraction::new(-1, 2).to_i64_saturating(), 0);
+        assert_eq!(Fraction::new(-1, 2).to_u32_saturating(), 0);
+        assert_eq!(Fraction::new(9, 4).to_u32_saturating(), 2);
+
+        // i32
+        assert_eq!(Fraction::MAX.to_i32_saturating(), i32::MAX - 1);
+        assert_eq!(Fraction::MIN.to_i32_saturating(), i32::MIN + 1);
+        assert_eq!(Fraction::INFINITY.to_i32_saturating(), i32::MAX);
+        assert_eq!(Fraction::NEG_INFINITY.to_i32_saturating(), i32::MIN);
+        assert_eq!(Fraction::NAN.to_i32_saturating(), 0);
+
+        // i64
+        assert_eq!(Fraction::MAX.to_i64_saturating(), i32::MAX as i64 - 1);
+        assert_eq!(Fraction::MIN.to_i64_saturating(), i32::MIN as i64 + 1);
+        assert_eq!(Fraction::INFINITY.to_i64_saturating(), i64::MAX);
+        assert_eq!(Fraction::NEG_INFINITY.to_i64_saturating(), i64::MIN);
+        assert_eq!(Fraction::NAN.to_i64_saturating(), 0);
+
+        // u32
+        assert_eq!(Fraction::MAX.to_u32_saturating(), i32::MAX as u32 - 1);
+        assert_eq!(Fraction::MIN.to_u32_saturating(), 0);
+        assert_eq!(Fraction::INFINITY.to_u32_saturating(), u32::MAX);
+        assert_eq!(Fraction::NEG_INFINITY.to_u32_saturating(), 0);
+        assert_eq!(Fraction::NAN.to_u32_saturating(), 0);
+        assert_eq!(Fraction::ZERO.to_u32_saturating(), 0);
+    }
+
+    #[test]
+    fn test_validate_invariants() {
+        let constants = [
+            Fraction::INFINITY, Fraction::NEG_INFINITY, Fraction::NAN, Fraction::ZERO, 
+            Fraction::MAX, Fraction::MIN, Fraction::MIN_POSITIVE, 
+        ];
+        for c in constants {
+            assert!(c.validate_invariants(), "{:?}", c);
+            assert!((-c).validate_invariants(), "{:?}", -c);
+            assert!(c.abs().validate_invariants(), "{:?}", c.abs());
+            assert!(c.reciprocal().validate_invariants(), "{:?}", c.reciprocal());
+        }
+
+        let random = || Fraction::new(rand::random_range(i32::MIN..=i32::MAX), rand::random_range(i32::MIN..=i32::MAX));
+        for _ in 0..1000 {
+            let (a, b) = (random(), random());
+            let results = [a, a + b, a - b, a * b, a / b, a.abs(), a.reciprocal(), a.mul_add(b, a), a.midpoint(b)];
+            for x in results {
+                assert!(x.validate_invariants(), "{:?}", x);
+            }
+            for op in [
+                |x: &mut Fraction, y: Fraction| *x += y, 
+                |x: &mut Fraction, y: Fraction| *x -= y, 
+                |x: &mut Fraction, y: Fraction| *x *= y, 
+                |x: &mut Fraction, y: Fraction| *x /= y, 
+            ] {
+                let mut x = a;
+                op(&mut x, b);
+                assert!(x.validate_invariants(), "{:?}", x);
+            }
+        }
+
+        // 所有特殊值之间的运算
+        assert!((-Fraction::MIN).is_infinity());
+        assert!(Fraction::MIN.abs().is_infinity());
+        // 减去 MIN 不经过 -MIN
+        assert_eq!(Fraction::from(-5) - Fraction::MIN, Fraction::from(i32::MAX - 5));
+        assert_eq!(Fraction::MIN - Fraction::MIN, Fraction::ZERO);
+        let mut x = Fraction::new(-1, 2);
+        x -= Fraction::MIN;
+        assert_eq!(x, Fraction::new(i32::MAX - 1, 1) + Fraction::new(1, 2));
+        assert!((Fraction::ZERO - Fraction::MIN).is_infinity());
+        assert_eq!(Fraction::from(-5).checked_sub(Fraction::MIN), Some(Fraction::from(i32::MAX - 5)));
+        let policy = PrecisionPolicy::new(10);
+        assert_eq!(Fraction::from(-5).sub_with(Fraction::MIN, policy), Fraction::from(i32::MAX - 5));
+        assert!((Fraction::ONE - Fraction::INFINITY).is_neg_infinity());
+        assert!((Fraction::NEG_INFINITY - Fraction::NEG_INFINITY).is_nan());
+
+        let values = constants.iter().copied().chain([Fraction::from(-3), Fraction::new(1, 2)]);
+        for a in values.clone() {
+            for b in values.clone() {
+                for x in [a + b, a - b, a * b, a / b] {
+                    assert!(x.validate_invariants(), "{:?} {:?} -> {:?}", a, b, x);
+                }
+                let mut x = a;
+                x -= b;
+                assert!(x.validate_invariants(), "{:?} {:?} -> {:?}", a, b, x);
+                assert!(x == a - b || (x.is_nan() && (a - b).is_nan()), "{:?} -= {:?}", a, b);
+                let mut x = a;
+                x *= b;
+                assert!(x.validate_invariants(), "{:?} {:?} -> {:?}", a, b, x);
+                assert!(x == a * b || (x.is_nan() && (a * b).is_nan()), "{:?} *= {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_atan2() {
+        let pi = std::f64::consts::PI;
+        assert!((f64::from(Fraction::PI) - pi).abs() < 1e-15);
+
+        // 文档中的误差上限，另加 f64 的舍入误差
+        let bound = |angle: f64| 1e-17 + angle.powi(2).max(1.0) / (2.0 * i32::MAX as f64) + 1e-15;
+        let points = [(1, 1), (1, -1), (-1, -1), (-1, 1), (3, 4), (-5, 12), (7, -24), (1, 1000), (-1000, -1)];
+        for (y, x) in points {
+            let angle = Fraction::atan2(Fraction::from(y), Fraction::from(x));
+            let expected = (y as f64).atan2(x as f64);
+            assert!((f64::from(angle) - expected).abs() < bound(expected), "atan2({}, {})", y, x);
+        }
+
+        // 与高精度计算得到的 i32 范围内最接近的分数相同
+        let one = Fraction::ONE;
+        assert_eq!(Fraction::atan2(one, one), Fraction::new(267241724, 340262731));
+        assert_eq!(Fraction::atan2(Fraction::from(-5), Fraction::from(12)), Fraction::new(-651090637, 1649202843));
+        assert_eq!(Fraction::atan2(-one, Fraction::from(-1000)), Fraction::new(-309665950, 98601119));
+        assert_eq!(Fraction::atan2(one, Fraction::ZERO), Fraction::new(2015008331, 1282794145));
+        assert_eq!(one.atan(), Fraction::atan2(one, one));
+        assert!((f64::from(Fraction::new(1, 3).atan()) - (1f64 / 3.0).atan()).abs() < 1e-15);
+
+        // 坐标轴
+        let zero = Fraction::ZERO;
+        assert!(Fraction::atan2(zero, one).is_zero());
+        assert!(Fraction::atan2(zero, zero).is_zero());
+        assert_eq!(Fraction::atan2(zero, -one), Fraction::PI);
+        assert!((f64::from(Fraction::atan2(one, zero)) - pi / 2.0).abs() < 1e-15);
+        assert!((f64::from(Fraction::atan2(-one, zero)) + pi / 2.0).abs() < 1e-15);
+
+        // 无穷与 f64::atan2 相同
+        let (inf, neg_inf) = (Fraction::INFINITY, Fraction::NEG_INFINITY);
+        for (y, x) in [(inf, one), (one, inf), (one, neg_inf), (inf, inf), (neg_inf, neg_inf), (zero, neg_inf), (neg_inf, zero)] {
+            let expected = f64::from(y).atan2(f64::from(x));
+            assert!((f64::from(Fraction::atan2(y, x)) - expected).abs() < 1e-15, "atan2({}, {})", y, x);
+        }
+
+        // 范围
+        for _ in 0..1000 {
+            let y = random_fraction();
+            let x = random_fraction();
+            let angle = Fraction::atan2(y, x);
+            let expected = f64::from(y).atan2(f64::from(x));
+            assert!(angle >= -Fraction::PI && angle <= Fraction::PI);
+            assert!((f64::from(angle) - expected).abs() < bound(expected), "atan2({}, {})", y, x);
+        }
+        assert!(Fraction::atan2(Fraction::NAN, one).is_nan());
+        assert!(Fraction::atan2(one, Fraction::NAN).is_nan());
+        assert!(Fraction::NAN.atan().is_nan());
+    }
+
+    #[test]
+    fn test_denominator_statistics() {
+        let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(Fraction::max_denominator(&values), 6);
+        assert_eq!(Fraction::lcd(&values), Some(6));
+
+        let values = [Fraction::new(3, 4), Fraction::ZERO, Fraction::from(5), Fraction::new(-1, 10)];
+        assert_eq!(Fraction::max_denominator(&values), 10);
+        assert_eq!(Fraction::lcd(&values), Some(20));
+        assert_eq!(Fraction::lcd(&[]), Some(1));
+
+        // 溢出
+        let values = [Fraction::new(1, 65536), Fraction::new(1, 65535)];
+        assert_eq!(Fraction::lcd(&values), None);
+        assert_eq!(Fraction::lcd(&[Fraction::MIN_POSITIVE, Fraction::new(1, 2)]), None);
+        assert_eq!(Fraction::lcd(&[Fraction::MIN_POSITIVE, Fraction::from(7)]), Some(i32::MAX));
+
+        // 非有限值
+        let values = [Fraction::new(1, 2), Fraction::INFINITY];
+        assert_eq!(Fraction::max_denominator(&values), 2);
+        assert_eq!(Fraction::lcd(&values), None);
+        assert_eq!(Fraction::max_denominator(&[Fraction::NAN]), 0);
+    }
+
+    #[test]
+    fn test_best_rational() {
+        for max_denom in [3, 4, 100, 1000] {
+            assert_eq!(Fraction::best_rational(0.3333333, max_denom), Fraction::new(1, 3));
+        }
+        assert_eq!(Fraction::best_rational(0.3333333, i32::MAX), Fraction::new(3333333, 10000000));
+        assert_eq!(Fraction::best_rational(0.3333333, 2), Fraction::new(1, 2));
+
+        let pi = std::f64::consts::PI;
+        assert_eq!(Fraction::best_rational(pi, 1), Fraction::from(3));
+        assert_eq!(Fraction::best_rational(pi, 10), Fraction::new(22, 7));
+        assert_eq!(Fraction::best_rational(pi, 106), Fraction::new(333, 106));
+        assert_eq!(Fraction::best_rational(pi, 112), Fraction::new(333, 106));
+        assert_eq!(Fraction::best_rational(pi, 113), Fraction::new(355, 113));
+        assert_eq!(Fraction::best_rational(-pi, 10), Fraction::new(-22, 7));
+
+        // 与暴力搜索比较：距离最近，距离相同时分母最小
+        for _ in 0..200 {
+            // 二进制小数，可以精确转换为分数并精确计算距离
+            let x = rand::random_range(-10_000_000..10_000_000) as f64 / (1 << 20) as f64;
+            let max_denom = rand::random_range(1..50);
+            let result = Fraction::best_rational(x, max_denom);
+            assert!(result.denominator() <= max_denom);
+            let distance = |f: Fraction| (f - Fraction::from_f64_exact(x).unwrap()).abs();
+            for deno in 1..=max_denom {
+                let nume = (x * deno as f64).floor() as i32;
+                for candidate in [Fraction::new(nume, deno), Fraction::new(nume + 1, deno)] {
+                    let (d, r) = (distance(candidate), distance(result));
+                    assert!(d > r || (d == r && candidate.denominator() >= result.denominator()), "{} {} {:?}", x, max_denom, candidate);
+                }
+            }
+        }
+
+        // 恰好位于中点
+        assert_eq!(Fraction::best_rational(0.25, 2), Fraction::ZERO);
+        assert_eq!(Fraction::best_rational(0.75, 2), Fraction::from(1));
+        assert_eq!(Fraction::best_rational(1.25, 2), Fraction::from(1));
+        assert_eq!(Fraction::best_rational(-0.25, 2), Fraction::ZERO);
+
+        assert_eq!(Fraction::best_rational(0.0, 5), Fraction::ZERO);
+        assert_eq!(Fraction::best_rational(1e-300, 5), Fraction::ZERO);
+        assert_eq!(Fraction::best_rational(7.0, 5), Fraction::from(7));
+        assert!(Fraction::best_rational(f64::NAN, 5).is_nan());
+        assert!(Fraction::best_rational(f64::NEG_INFINITY, 5).is_neg_infinity());
+        assert!(Fraction::best_rational(1e20, 5).is_infinity());
+    }
+
+    #[test]
+    fn test_reduce_in_place() {
+        let pairs = [
+            (4, -6), (-6, -4), (0, 7), (0, -7), (5, 0), (-5, 0), (0, 0), (i32::MIN, 1), (i32::MIN, -1), 
+            (i32::MAX, 1), (i32::MIN, i32::MIN), (i32::MIN, 6), (12, i32::MIN), (i32::MAX - 1, -1), 
+        ];
+        for (nume, deno) in pairs {
+            let mut a = Fraction::new_unchecked(nume, deno);
+            a.reduce_in_place();
+            assert!(a.validate_invariants(), "{} {}", nume, deno);
+            assert!(a == Fraction::new(nume, deno) || (a.is_nan() && Fraction::new(nume, deno).is_nan()), "{} {}", nume, deno);
+        }
+
+        for _ in 0..1000 {
+            let (nume, deno) = (rand::random_range(i32::MIN..=i32::MAX), rand::random_range(i32::MIN..=i32::MAX));
+            let mut a = Fraction::new_unchecked(nume, deno);
+            a.reduce_in_place();
+            assert!(a.validate_invariants());
+            assert_eq!(a, Fraction::new(nume, deno));
+
+            // 已是最简形式时不变
+            let b = a;
+            a.reduce_in_place();
+            assert_eq!(a, b);
+        }
+
+        const HALF: Fraction = Fraction::new_unchecked(1, 2);
+        assert!(HALF.validate_invariants());
+        assert!(!Fraction::new_unchecked(2, 4).validate_invariants());
+    }
+
+    #[test]
+    fn test_components() {
+        assert_eq!(Fraction::new(7, 3).components(), (2, 1, 3));
+        assert_eq!(Fraction::new(-7, 3).components(), (-2, 1, 3));
+        assert_eq!(Fraction::new(1, 3).components(), (0, 1, 3));
+        assert_eq!(Fraction::new(-1, 3).components(), (0, 1, 3));
+        assert_eq!(Fraction::from(5).components(), (5, 0, 1));
+        assert_eq!(Fraction::from(-5).components(), (-5, 0, 1));
+        assert_eq!(Fraction::ZERO.components(), (0, 0, 1));
+        assert_eq!(Fraction::MIN.components(), (i32::MIN + 1, 0, 1));
+        assert_eq!(Fraction::new(-(i32::MAX), 2).components(), (-(i32::MAX / 2), 1, 2));
+
+        assert_eq!(Fraction::INFINITY.components(), (i32::MAX, 0, 1));
+        assert_eq!(Fraction::NEG_INFINITY.components(), (i32::MIN, 0, 1));
+        assert_eq!(Fraction::NAN.components(), (0, 0, 0));
+
+        // 由各部分可以还原
+        for _ in 0..1000 {
+            let a = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..1000));
+            let (whole, nume, deno) = a.components();
+            assert!(nume < deno);
+            let proper = Fraction::new(nume as i32, deno as i32);
+            let restored = if a.is_negative() { Fraction::from(whole) - proper } else { Fraction::from(whole) + proper };
+            assert_eq!(restored, a);
+        }
+    }
+
+    #[test]
+    fn test_approx_key() {
+        use std::collections::HashSet;
+
+        // README 中的例子
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert!(a != b);
+        let mut set: HashSet<ApproxKey> = HashSet::new();
+        assert!(set.insert(ApproxKey(a)));
+        assert!(!set.insert(ApproxKey(b)));
+        assert_eq!(set.len(), 1);
+
+        let a = Fraction::new(i32::MAX - 1, i32::MAX);
+        let b = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        let set: HashSet<ApproxKey> = [a, b].into_iter().map(ApproxKey).collect();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&ApproxKey(Fraction::from(1))));
+
+        // 运算后的近似结果
+        let third = Fraction::new(1, 3);
+        let x = Fraction::new(1, 6) + Fraction::new(1, 6);
+        let y = (Fraction::MAX / 3) / Fraction::MAX;
+        let set: HashSet<ApproxKey> = [third, x, y].into_iter().map(ApproxKey::from).collect();
+        assert_eq!(set.len(), 1);
+
+        // 不同的值仍然不同
+        let set: HashSet<ApproxKey> = [Fraction::new(1, 3), Fraction::new(1, 2), -Fraction::new(1, 3)]
+            .into_iter().map(ApproxKey).collect();
+        assert_eq!(set.len(), 3);
+
+        // 分母上限
+        assert!(ApproxKey::<100>(Fraction::new(1, 3)) == ApproxKey(Fraction::new(333, 1000)));
+        assert!(ApproxKey::<1000>(Fraction::new(1, 3)) != ApproxKey(Fraction::new(333, 1000)));
+        assert_eq!(ApproxKey::<7>(Fraction::new(355, 113)).canonical(), Fraction::new(22, 7));
+
+        // 特殊值
+        assert!(ApproxKey::<10>(Fraction::NAN) == ApproxKey(Fraction::NAN));
+        assert!(ApproxKey::<10>(Fraction::INFINITY) == ApproxKey(Fraction::INFINITY));
+        assert!(ApproxKey::<10>(Fraction::INFINITY) != ApproxKey(Fraction::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_gcd_extended() {
+        let pairs = [
+            (240, 46), (46, 240), (-240, 46), (240, -46), (-240, -46), (17, 5), (1, 1), (6, 6), 
+            (0, 5), (5, 0), (0, -5), (-5, 0), (0, 0), (i32::MAX, i32::MAX - 1), (i32::MIN, 3), (i32::MIN, i32::MAX), 
+        ];
+        for (a, b) in pairs {
+            let (g, x, y) = Fraction::gcd_extended(a, b);
+            assert_eq!(a as i64 * x as i64 + b as i64 * y as i64, g as i64, "{} {}", a, b);
+            assert_eq!(g as u32, Fraction::gcd_extended(b, a).0 as u32);
+            assert!(g >= 0);
+        }
+        assert_eq!(Fraction::gcd_extended(240, 46).0, 2);
+        assert_eq!(Fraction::gcd_extended(-5, 0), (5, -1, 0));
+        assert_eq!(Fraction::gcd_extended(0, 0), (0, 1, 0));
+        assert_eq!(Fraction::gcd_extended(i32::MIN, 6).0, 2);
+
+        for _ in 0..1000 {
+            let (a, b) = (rand::random_range(i32::MIN..=i32::MAX), rand::random_range(i32::MIN..=i32::MAX));
+            let (g, x, y) = Fraction::gcd_extended(a, b);
+            assert_eq!(a as i64 * x as i64 + b as i64 * y as i64, g as i64);
+            assert!(g > 0 && a % g == 0 && b % g == 0);
+        }
+    }
+
+    #[test]
+    fn test_display_over() {
+        // 精确
+        assert_eq!(Fraction::new(1, 2).display_over(12), "6/12");
+        assert_eq!(Fraction::new(3, 4).display_over(12), "9/12");
+        assert_eq!(Fraction::new(-5, 6).display_over(12), "-10/12");
+        assert_eq!(Fraction::from(2).display_over(12), "24/12");
+        assert_eq!(Fraction::ZERO.display_over(12), "0/12");
+        assert_eq!(Fraction::new(1, 2).display_over(1), "0/1");
+        assert_eq!(Fraction::new(3, 2).display_over(1), "2/1");
+
+        // 舍入
+        assert_eq!(Fraction::new(1, 5).display_over(12), "2/12");
+        assert_eq!(Fraction::new(1, 3).display_over(10), "3/10");
+        assert_eq!(Fraction::new(2, 3).display_over(10), "7/10");
+        assert_eq!(Fraction::new(-2, 3).display_over(10), "-7/10");
+        assert_eq!(Fraction::new(1, 24).display_over(12), "0/12");
+        assert_eq!(Fraction::new(-1, 100).display_over(12), "0/12");
+        assert_eq!(Fraction::new(3, 24).display_over(12), "2/12");
+
+        // 超出 i32 范围的分子
+        assert_eq!(Fraction::MAX.display_over(i32::MAX), format!("{}/{}", (i32::MAX as i64 - 1) * i32::MAX as i64, i32::MAX));
+
+        assert_eq!(Fraction::INFINITY.display_over(12), "inf");
+        assert_eq!(Fraction::NEG_INFINITY.display_over(12), "-inf");
+        assert_eq!(Fraction::NAN.display_over(12), Fraction::NAN.to_string());
+    }
+
+    #[test]
+    fn test_add_reporting() {
+        assert_eq!(Fraction::new(1, 2).add_reporting(Fraction::new(1, 3)), (Fraction::new(5, 6), false));
+        assert_eq!(Fraction::from(3).add_reporting(Fraction::ZERO), (Fraction::from(3), false));
+        assert_eq!(Fraction::MAX.add_reporting(Fraction::MIN), (Fraction::from(-1), false));
+
+        // 分母近似互质，精确的和超出范围
+        let a = Fraction::new(1, 2147483629);
+        let b = Fraction::new(1, 2147483587);
+        let (sum, approximated) = a.add_reporting(b);
+        assert!(approximated);
+        assert_eq!(sum, a + b);
+
+        // 溢出
+        assert_eq!(Fraction::MAX.add_reporting(Fraction::from(1)), (Fraction::INFINITY, true));
+        assert_eq!(Fraction::MAX.add_reporting(Fraction::MAX), (Fraction::INFINITY, true));
+        assert_eq!(Fraction::MIN.add_reporting(Fraction::from(-1)), (Fraction::MIN, true));
+
+        // 特殊值
+        assert_eq!(Fraction::INFINITY.add_reporting(Fraction::from(1)), (Fraction::INFINITY, false));
+        let (nan, approximated) = Fraction::INFINITY.add_reporting(Fraction::NEG_INFINITY);
+        assert!(nan.is_nan() && !approximated);
+
+        // 与 checked_sub 一致
+        for _ in 0..1000 {
+            let a = random_fraction();
+            let b = random_fraction();
+            let (sum, approximated) = a.add_reporting(b);
+            assert_eq!(sum, a + b);
+            assert_eq!(approximated, a.checked_sub(-b).is_none());
+        }
+    }
+
+    #[test]
+    fn test_assign_by_reference() {
+        let values: Vec<Fraction> = (1..=10).map(|n| Fraction::new(1, n * (n + 1))).collect();
+        let mut total = Fraction::ZERO;
+        for v in values.iter() {
+            total += v;
+        }
+        assert_eq!(total, Fraction::new(10, 11));
+
+        let mut product = Fraction::from(1);
+        for v in &values {
+            product *= v;
+        }
+        assert_eq!(product, values.iter().product::<Fraction>());
+
+        // 以引用逐项读取另一个切片，原地修改
+        let mut targets = vec![Fraction::from(1); 4];
+        let operands = [Fraction::new(1, 2), Fraction::new(2, 3), Fraction::new(-3, 4), Fraction::ZERO];
+        for (t, o) in targets.iter_mut().zip(&operands) {
+            *t -= o;
+        }
+        assert_eq!(targets, [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(7, 4), Fraction::from(1)]);
+        for (t, o) in targets.iter_mut().zip(&operands) {
+            *t /= o;
+        }
+        assert_eq!(targets[..3], [Fraction::from(1), Fraction::new(1, 2), Fraction::new(-7, 3)]);
+        assert!(targets[3].is_infinity());
+    }
+
+    #[test]
+    fn test_to_grouped_string() {
+        assert_eq!(Fraction::new(1234567, 8).to_grouped_string(','), "1,234,567/8");
+        assert_eq!(Fraction::new(-1234567, 1000).to_grouped_string(','), "-1,234,567/1,000");
+        assert_eq!(Fraction::from(1234567).to_grouped_string(','), "1,234,567");
+        assert_eq!(Fraction::from(-100).to_grouped_string(','), "-100");
+        assert_eq!(Fraction::from(100000).to_grouped_string(' '), "100 000");
+        assert_eq!(Fraction::new(7, 12).to_grouped_string(','), "7/12");
+        assert_eq!(Fraction::MAX.to_grouped_string('\''), "2'147'483'646");
+        assert_eq!(Fraction::MIN_POSITIVE.to_grouped_string(','), "1/2,147,483,647");
+        assert_eq!(Fraction::new(1, 1000).to_grouped_string('.'), "1/1.000");
+
+        assert_eq!(Fraction::ZERO.to_grouped_string(','), "0");
+        assert_eq!(Fraction::INFINITY.to_grouped_string(','), "inf");
+        assert_eq!(Fraction::NEG_INFINITY.to_grouped_string(','), "-inf");
+        assert_eq!(Fraction::NAN.to_grouped_string(','), "nan");
+
+        // 去掉分隔符后与 Display 相同
+        for _ in 0..1000 {
+            let a = random_fraction();
+            assert_eq!(a.to_grouped_string(',').replace(',', ""), a.to_string());
+        }
+    }
+
+    #[test]
+    fn test_add_exact() {
+        // 精确
+        assert_eq!(Fraction::new(1, 2).add_exact(Fraction::new(1, 3)), Ok(Fraction::new(5, 6)));
+        assert_eq!(Fraction::new(-1, 2).add_exact(Fraction::new(1, 2)), Ok(Fraction::ZERO));
+        assert_eq!(Fraction::MAX.add_exact(Fraction::MIN), Ok(Fraction::from(-1)));
+        assert_eq!(Fraction::MAX.add_exact(Fraction::ZERO), Ok(Fraction::MAX));
+
+        // 近似
+        let a = Fraction::new(1, 2147483629);
+        let b = Fraction::new(1, 2147483587);
+        assert_eq!(a.add_exact(b), Err(a + b));
+        assert_eq!(Fraction::MAX.add_exact(Fraction::from(1)), Err(Fraction::INFINITY));
+        assert_eq!(Fraction::MIN.add_exact(Fraction::from(-1)), Err(Fraction::MIN));
+
+        // README 中的例子：两者之差不能精确表示
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert_eq!(a.add_exact(-b), Err(Fraction::ZERO));
+
+        assert_eq!(Fraction::INFINITY.add_exact(Fraction::from(1)), Ok(Fraction::INFINITY));
+        assert!(Fraction::NAN.add_exact(Fraction::from(1)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_fit_denominator() {
+        let pi = Fraction::new(355, 113);
+        let fitted = pi.fit_denominator(100);
+        assert!(fitted.denominator() <= 100);
+        assert_eq!(fitted, Fraction::new(311, 99));
+        assert_eq!(pi.fit_denominator(113), pi);
+        assert_eq!(pi.fit_denominator(7), Fraction::new(22, 7));
+
+        // 通过 u16 字段往返
+        for _ in 0..1000 {
+            let a = Fraction::new(rand::random_range(-1000000..1000000), rand::random_range(1..=i32::MAX));
+            let max_denom = rand::random_range(1..=u16::MAX);
+            let fitted = a.fit_denominator(max_denom);
+            let deno = u16::try_from(fitted.denominator()).unwrap();
+            assert!(deno <= max_denom);
+            assert_eq!(Fraction::new(fitted.numerator(), deno as i32), fitted);
+            assert!((f64::from(fitted) - f64::from(a)).abs() <= 1.0 / max_denom as f64);
+        }
+
+        assert!(Fraction::INFINITY.fit_denominator(10).is_infinity());
+        assert!(Fraction::NAN.fit_denominator(10).is_nan());
+        assert_eq!(Fraction::ZERO.fit_denominator(1), Fraction::ZERO);
+    }
+
+    #[test]
+    fn test_cf_metrics() {
+        assert_eq!(Fraction::new(7, 3).cf_length(), 2);
+        assert_eq!(Fraction::new(7, 3).cf_sum(), 5);
+        assert_eq!(Fraction::from(1).cf_length(), 1);
+        assert_eq!(Fraction::from(1).cf_sum(), 1);
+        assert_eq!(Fraction::from(-4).cf_sum(), -4);
+        assert_eq!(Fraction::ZERO.cf_length(), 1);
+        assert_eq!(Fraction::ZERO.cf_sum(), 0);
+        assert_eq!(Fraction::new(1, 2).cf_length(), 2);
+        assert_eq!(Fraction::new(103993, 33102).cf_length(), 5);
+        assert_eq!(Fraction::MIN_POSITIVE.cf_sum(), i32::MAX as i64);
+
+        // 相邻斐波那契数之比的各项几乎均为 1，项数最多
+        let fib = Fraction::new(1134903170, 1836311903);
+        assert_eq!(fib.cf_length(), 45);
+        assert_eq!(fib.cf_sum(), 45);
+
+        for special in [Fraction::INFINITY, Fraction::NEG_INFINITY, Fraction::NAN] {
+            assert_eq!(special.cf_length(), 0);
+            assert_eq!(special.cf_sum(), 0);
+        }
+
+        for _ in 0..1000 {
+            let a = random_fraction();
+            assert_eq!(a.cf_length(), a.convergents().count());
+        }
+    }
+
+    #[test]
+    fn test_saturating_pow() {
+        // 范围内
+        assert_eq!(Fraction::new(2, 3).saturating_pow(5), Fraction::new(32, 243));
+        assert_eq!(Fraction::new(-2, 3).saturating_pow(-3), Fraction::new(-27, 8));
+        assert_eq!(Fraction::from(2).saturating_pow(30), Fraction::from(1 << 30));
+        assert_eq!(Fraction::new(7, 5).saturating_pow(0), Fraction::from(1));
+
+        // 溢出
+        assert_eq!(Fraction::from(2).saturating_pow(40), Fraction::MAX);
+        assert_eq!(Fraction::from(2).saturating_pow(31), Fraction::MAX);
+        assert_eq!(Fraction::from(-2).saturating_pow(31), Fraction::MIN);
+        assert_eq!(Fraction::from(-2).saturating_pow(32), Fraction::MAX);
+        assert_eq!(Fraction::new(1, 3).saturating_pow(-100), Fraction::MAX);
+        assert_eq!(Fraction::MIN_POSITIVE.saturating_pow(-1), Fraction::MAX);
+        assert_eq!(Fraction::ZERO.saturating_pow(-2), Fraction::MAX);
+        assert_eq!(Fraction::INFINITY.saturating_pow(2), Fraction::MAX);
+        assert_eq!(Fraction::NEG_INFINITY.saturating_pow(3), Fraction::MIN);
+
+        // 下溢
+        assert!(Fraction::new(1, 2).saturating_pow(40).is_zero());
+        assert!(Fraction::from(3).saturating_pow(-50).is_zero());
+        assert!(Fraction::INFINITY.saturating_pow(-1).is_zero());
+        let tiny = Fraction::new(1, 2).saturating_pow(30);
+        assert_eq!(tiny, Fraction::new(1, 1 << 30));
+
+        // 近似
+        let x = Fraction::new(11, 10).saturating_pow(100);
+        assert!((f64::from(x) / 1.1f64.powi(100) - 1.0).abs() < 1e-9);
+        let x = Fraction::new(-9, 10).saturating_pow(101);
+        assert!((f64::from(x) / 0.9f64.powi(101) + 1.0).abs() < 1e-9);
+
+        assert!(Fraction::NAN.saturating_pow(0).is_nan());
+        for _ in 0..100 {
+            let a = Fraction::new(rand::random_range(-100..100), rand::random_range(1..100));
+            let exp = rand::random_range(-50..50);
+            assert!(!a.saturating_pow(exp).is_infinity() && !a.saturating_pow(exp).is_neg_infinity());
+        }
+    }
+
+    #[test]
+    fn test_ratio_string() {
+        assert_eq!(Fraction::from(5).to_ratio_string(), "5/1");
+        assert_eq!(Fraction::from(-5).to_ratio_string(), "-5/1");
+        assert_eq!(Fraction::new(3, 4).to_ratio_string(), "3/4");
+        assert_eq!(Fraction::new(6, -8).to_ratio_string(), "-3/4");
+        assert_eq!(Fraction::ZERO.to_ratio_string(), "0/1");
+        assert_eq!(Fraction::INFINITY.to_ratio_string(), "inf");
+        assert_eq!(Fraction::NEG_INFINITY.to_ratio_string(), "-inf");
+        assert_eq!(Fraction::NAN.to_ratio_string(), "nan");
+
+        let s: String = Fraction::new(3, 4).into();
+        assert_eq!(s, "3/4");
+        assert_eq!(String::from(Fraction::from(5)), "5");
+        assert_eq!(String::from(Fraction::INFINITY), "inf");
+
+        // 可以解析回原值
+        for _ in 0..1000 {
+            let a = random_fraction();
+            assert_eq!(a.to_ratio_string().parse::<Fraction>(), Ok(a));
+        }
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(Fraction::new(12, 35).factorize(), Some((vec![(2, 2), (3, 1)], vec![(5, 1), (7, 1)])));
+        assert_eq!(Fraction::new(-24, 70).factorize(), Some((vec![(2, 2), (3, 1)], vec![(5, 1), (7, 1)])));
+        // 质数 / 质数
+        assert_eq!(Fraction::new(13, 17).factorize(), Some((vec![(13, 1)], vec![(17, 1)])));
+        assert_eq!(Fraction::MIN_POSITIVE.factorize(), Some((vec![], vec![(i32::MAX, 1)])));
+        assert_eq!(Fraction::from(1).factorize(), Some((vec![], vec![])));
+        assert_eq!(Fraction::from(1 << 30).factorize(), Some((vec![(2, 30)], vec![])));
+        assert_eq!(Fraction::new(1, 46327 * 46349).factorize(), Some((vec![], vec![(46327, 1), (46349, 1)])));
+
+        for special in [Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, Fraction::NAN] {
+            assert_eq!(special.factorize(), None);
+        }
+
+        // 各因数之积还原为分子分母
+        let product = |factors: &[(i32, i32)]| factors.iter().map(|&(p, e)| (p as i64).pow(e as u32)).product::<i64>();
+        for _ in 0..200 {
+            let a = random_fraction();
+            if a.is_zero() {
+                continue;
+            }
+            let (nume, deno) = a.factorize().unwrap();
+            assert_eq!(product(&nume), a.numerator().unsigned_abs() as i64);
+            assert_eq!(product(&deno), a.denominator() as i64);
+            assert!(nume.windows(2).all(|w| w[0].0 < w[1].0));
+        }
+    }
+
+    #[test]
+    fn test_terminating_decimal() {
+        assert!(Fraction::new(3, 8).is_terminating_decimal());
+        assert_eq!(Fraction::new(3, 8).terminating_length(), Some(3));
+        assert!(Fraction::new(7, 20).is_terminating_decimal());
+        assert_eq!(Fraction::new(7, 20).terminating_length(), Some(2));
+        assert!(!Fraction::new(1, 3).is_terminating_decimal());
+        assert_eq!(Fraction::new(1, 3).terminating_length(), None);
+
+        assert_eq!(Fraction::new(-1, 2).terminating_length(), Some(1));
+        assert_eq!(Fraction::new(1, 3125).terminating_length(), Some(5));
+        assert_eq!(Fraction::new(1, 1 << 30).terminating_length(), Some(30));
+        assert_eq!(Fraction::new(1, 1000000000).terminating_length(), Some(9));
+        assert_eq!(Fraction::new(1, 6).terminating_length(), None);
+        assert_eq!(Fraction::new(1, 14).terminating_length(), None);
+        assert_eq!(Fraction::from(42).terminating_length(), Some(0));
+        assert_eq!(Fraction::ZERO.terminating_length(), Some(0));
+
+        for special in [Fraction::INFINITY, Fraction::NEG_INFINITY, Fraction::NAN] {
+            assert!(!special.is_terminating_decimal());
+            assert_eq!(special.terminating_length(), None);
+        }
+
+        // 乘以 10^n 后为整数，且 n 最小
+        for _ in 0..1000 {
+            let deno = 2i32.pow(rand::random_range(0..8)) * 5i32.pow(rand::random_range(0..6));
+            let a = Fraction::new(rand::random_range(-1000..1000), deno);
+            let n = a.terminating_length().unwrap();
+            assert_eq!((a * 10i32.pow(n as u32)).denominator(), 1);
+            if n > 0 {
+                assert_ne!((a * 10i32.pow(n as u32 - 1)).denominator(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_to_denom() {
+        let third = Fraction::new(1, 3);
+        assert_eq!(third.round_down_to_denom(4), Fraction::new(1, 4));
+        assert_eq!(third.round_up_to_denom(4), Fraction::new(1, 2));
+        assert_eq!((-third).round_down_to_denom(4), Fraction::new(-1, 2));
+        assert_eq!((-third).round_up_to_denom(4), Fraction::new(-1, 4));
+        assert_eq!(third.round_down_to_denom(3), third);
+        assert_eq!(third.round_up_to_denom(3), third);
+        assert_eq!(third.round_down_to_denom(1), Fraction::ZERO);
+        assert_eq!(third.round_up_to_denom(1), Fraction::from(1));
+        assert_eq!(Fraction::new(-7, 2).round_down_to_denom(1), Fraction::from(-4));
+        assert_eq!(Fraction::new(-7, 2).round_up_to_denom(1), Fraction::from(-3));
+        assert_eq!(Fraction::ZERO.round_up_to_denom(7), Fraction::ZERO);
+        assert_eq!(Fraction::MAX.round_up_to_denom(i32::MAX), Fraction::MAX);
+        assert_eq!(Fraction::MIN_POSITIVE.round_up_to_denom(2), Fraction::new(1, 2));
+        assert_eq!(Fraction::MIN_POSITIVE.round_down_to_denom(2), Fraction::ZERO);
+
+        // 上下界夹住原值，且相差不超过一格
+        for _ in 0..1000 {
+            let a = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..100000));
+            let denom = rand::random_range(1..1000);
+            let (lo, hi) = (a.round_down_to_denom(denom), a.round_up_to_denom(denom));
+            assert!(lo <= a && a <= hi);
+            assert!(hi - lo == Fraction::ZERO || hi - lo == Fraction::new(1, denom));
+            assert_eq!((lo * denom).denominator(), 1);
+            assert_eq!((hi * denom).denominator(), 1);
+        }
+
+        // 网格上的值超出范围而需要近似时，仍保证方向
+        let a = Fraction::new(1000000001, 3);
+        let (lo, hi) = (a.round_down_to_denom(i32::MAX), a.round_up_to_denom(i32::MAX));
+        assert!(lo < a && a < hi);
+        assert!((-a).round_down_to_denom(i32::MAX) == -hi && (-a).round_up_to_denom(i32::MAX) == -lo);
+        for _ in 0..1000 {
+            let a = random_fraction();
+            let denom = rand::random_range(1..=i32::MAX);
+            let (lo, hi) = (a.round_down_to_denom(denom), a.round_up_to_denom(denom));
+            assert!(lo <= a && a <= hi, "{} {} {} {}", a, denom, lo, hi);
+            assert!(lo.validate_invariants() && hi.validate_invariants());
+        }
+
+        assert!(Fraction::INFINITY.round_down_to_denom(4).is_infinity());
+        assert!(Fraction::NAN.round_up_to_denom(4).is_nan());
+    }
+
+    #[test]
+    fn test_add_near_i64_limit() {
+        // 精确的和，以 i128 计算并化简后由 from_ratio_u64 shrink
+        let exact_sum = |x: Fraction, y: Fraction| {
+            let (a, b) = (x.numerator() as i128, x.denominator() as i128);
+            let (c, d) = (y.numerator() as i128, y.denominator() as i128);
+            let nume = a * d + c * b;
+            let deno = b * d;
+            let sign = nume.signum() as i8;
+            Fraction::from_ratio_u64(nume.unsigned_abs() as u64, deno as u64, sign)
+        };
+
+        // 分母接近 i32::MAX 且互质，分子也接近 i32::MAX，分子之和接近 2^63
+        let big = [i32::MAX, i32::MAX - 1, i32::MAX - 2, i32::MAX - 3, i32::MAX - 4];
+        for &p in &big {
+            for &q in &big {
+                for (x, y) in [
+                    (Fraction::new(p - 1, p), Fraction::new(q - 1, q)), 
+                    (Fraction::new(-(p - 1), p), Fraction::new(-(q - 1), q)), 
+                    (Fraction::new(p - 1, p), Fraction::new(-(q - 2), q)), 
+                    (Fraction::new(i32::MAX - 1, p), Fraction::new(i32::MAX - 1, q)), 
+                ] {
+                    assert_eq!(x + y, exact_sum(x, y), "{:?} + {:?}", x, y);
+                    assert_eq!(x - -y, exact_sum(x, y), "{:?} - {:?}", x, -y);
+                }
+            }
+        }
+
+        let x = Fraction::new(i32::MAX - 1, i32::MAX);
+        let y = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        let sum = x + y;
+        assert!((f64::from(sum) - (f64::from(x) + f64::from(y))).abs() < 1e-15);
+        assert!(x.add_reporting(y).1);
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(i32::MIN + 1..=i32::MAX - 1), rand::random_range(i32::MAX - 1000..=i32::MAX));
+            let y = Fraction::new(rand::random_range(i32::MIN + 1..=i32::MAX - 1), rand::random_range(i32::MAX - 1000..=i32::MAX));
+            assert_eq!(x + y, exact_sum(x, y));
+        }
+    }
+
+    #[test]
+    fn test_calkin_wilf() {
+        let terms: Vec<Fraction> = Fraction::calkin_wilf().take(8).collect();
+        assert_eq!(terms, [
+            Fraction::from(1), Fraction::new(1, 2), Fraction::from(2), Fraction::new(1, 3), 
+            Fraction::new(3, 2), Fraction::new(2, 3), Fraction::from(3), Fraction::new(1, 4), 
+        ]);
+
+        // 前 2^k - 1 项恰为 Calkin-Wilf 树的前 k 层，包含所有分子分母之和不超过 k + 1 的正有理数
+        let terms: Vec<Fraction> = Fraction::calkin_wilf().take((1 << 12) - 1).collect();
+        let distinct: std::collections::HashSet<Fraction> = terms.iter().copied().collect();
+        assert_eq!(distinct.len(), terms.len());
+        for q in 1..=12 {
+            for p in 1..=13 - q {
+                assert!(distinct.contains(&Fraction::new(p, q)), "{}/{}", p, q);
+            }
+        }
+        assert!(terms.iter().all(|x| x.is_positive() && x.validate_invariants()));
+
+        // 第 2^k - 1 项（从 1 开始计）为 k
+        assert_eq!(Fraction::calkin_wilf().nth((1 << 20) - 2), Some(Fraction::from(20)));
+    }
+
+    #[test]
+    fn test_is_multiple_of() {
+        let half = Fraction::new(1, 2);
+        assert!(Fraction::new(3, 2).is_multiple_of(&half));
+        assert!(Fraction::from(2).is_multiple_of(&half));
+        assert!(Fraction::new(-3, 2).is_multiple_of(&half));
+        assert!(Fraction::new(3, 2).is_multiple_of(&-half));
+        assert!(Fraction::new(3, 4).is_multiple_of(&Fraction::new(3, 8)));
+        assert!(half.is_multiple_of(&half));
+        assert!(!Fraction::new(2, 3).is_multiple_of(&half));
+        assert!(!half.is_multiple_of(&Fraction::from(1)));
+        assert!(!Fraction::new(3, 4).is_multiple_of(&Fraction::new(1, 3)));
+        assert!(Fraction::MAX.is_multiple_of(&Fraction::MIN_POSITIVE));
+        assert!(!Fraction::MIN_POSITIVE.is_multiple_of(&Fraction::MAX));
+        assert!(!Fraction::MIN.is_multiple_of(&Fraction::MAX));
+
+        // 零
+        assert!(Fraction::ZERO.is_multiple_of(&half));
+        assert!(!half.is_multiple_of(&Fraction::ZERO));
+        assert!(!Fraction::ZERO.is_multiple_of(&Fraction::ZERO));
+
+        // 非有限值
+        assert!(!Fraction::INFINITY.is_multiple_of(&half));
+        assert!(!half.is_multiple_of(&Fraction::INFINITY));
+        assert!(!Fraction::NAN.is_multiple_of(&half));
+
+        for _ in 0..1000 {
+            let unit = Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000));
+            let k = rand::random_range(-1000..1000);
+            if unit.is_zero() {
+                continue;
+            }
+            assert!((unit * k).is_multiple_of(&unit));
+            let offset = unit * k + Fraction::new(1, unit.denominator() * 2);
+            assert!(!offset.is_multiple_of(&unit));
+        }
+    }
+
+    #[test]
+    fn test_precision_policy() {
+        let tight = PrecisionPolicy::new(1000);
+        let (a, b) = (Fraction::new(1, 9973), Fraction::new(1, 9967));
+        let loose = a + b;
+        let coarse = a.add_with(b, tight);
+        assert!(coarse.denominator() <= 1000);
+        assert!(coarse.denominator() < loose.denominator());
+        assert!((coarse - loose).abs() < Fraction::new(1, 1000));
+        assert!(a.mul_with(b, tight).is_zero());
+
+        let default = PrecisionPolicy::default();
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-10000..10000), rand::random_range(1..10000));
+            let y = Fraction::new(rand::random_range(-10000..10000), rand::random_range(1..10000));
+            assert_eq!(x.add_with(y, default), x + y);
+            assert_eq!(x.sub_with(y, default), x - y);
+            assert_eq!(x.mul_with(y, default), x * y);
+            assert_eq!(x.div_with(y, default), x / y);
+        }
+
+        assert!(Fraction::INFINITY.add_with(Fraction::NEG_INFINITY, tight).is_nan());
+        assert_eq!(Fraction::from(1).div_with(Fraction::ZERO, tight), Fraction::INFINITY);
+        assert_eq!(Fraction::MAX.sub_with(Fraction::MIN, tight), Fraction::MAX - Fraction::MIN);
+
+        // 分母上限只能经由 new 设置，总是正数
+        assert_eq!(tight.max_denom(), 1000);
+        assert_eq!(default.max_denom(), i32::MAX);
+        assert!(std::panic::catch_unwind(|| PrecisionPolicy::new(0)).is_err());
+        assert!(std::panic::catch_unwind(|| PrecisionPolicy::new(-1)).is_err());
+    }
+
+    #[test]
+    fn test_eq_exact() {
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert!(!a.eq_exact(&b));
+        assert!(a.same_shrunk(b));
+        assert_eq!(a - b, Fraction::ZERO);
+
+        assert!(a.eq_exact(&Fraction::new(155937625 * 2, 24970004 * 2)));
+        assert!(Fraction::new(-3, -6).eq_exact(&Fraction::new(1, 2)));
+        assert!(!Fraction::new(1, 2).eq_exact(&Fraction::new(-1, 2)));
+        assert!(Fraction::INFINITY.eq_exact(&Fraction::new(1, 0)));
+        assert!(!Fraction::INFINITY.eq_exact(&Fraction::MAX));
+        assert!(Fraction::NAN.eq_exact(&Fraction::new(0, 0)));
+        assert!(Fraction::ZERO.eq_exact(&Fraction::new(0, -5)));
+    }
+
+    #[test]
+    fn test_cmp_sum_zero() {
+        use std::cmp::Ordering;
+
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert_eq!(a - b, Fraction::ZERO);
+        assert_eq!(Fraction::cmp_sum_zero(a, -b), Some(Ordering::Greater));
+        assert_eq!(Fraction::cmp_sum_zero(-a, b), Some(Ordering::Less));
+        assert_eq!(Fraction::cmp_sum_zero(a, -a), Some(Ordering::Equal));
+
+        let (x, y) = (Fraction::new(i32::MAX - 2, i32::MAX - 1), Fraction::new(i32::MAX - 3, i32::MAX - 2));
+        assert_eq!(x - y, Fraction::ZERO);
+        assert_eq!(Fraction::cmp_sum_zero(x, -y), Some(Ordering::Greater));
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::MAX, Fraction::MAX), Some(Ordering::Greater));
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::MIN, Fraction::MIN), Some(Ordering::Less));
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::ZERO, Fraction::ZERO), Some(Ordering::Equal));
+
+        for _ in 0..1000 {
+            let p = Fraction::new(rand::random_range(-10000..10000), rand::random_range(1..10000));
+            let q = Fraction::new(rand::random_range(-10000..10000), rand::random_range(1..10000));
+            assert_eq!(Fraction::cmp_sum_zero(p, q), (p + q).partial_cmp(&Fraction::ZERO));
+        }
+
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::INFINITY, Fraction::MIN), Some(Ordering::Greater));
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::NAN, Fraction::ZERO), None);
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::INFINITY, Fraction::NEG_INFINITY), None);
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::NEG_INFINITY, Fraction::INFINITY), None);
+        assert_eq!(Fraction::cmp_sum_zero(Fraction::ONE, Fraction::NAN), None);
+    }
+
+    #[cfg(feature = "stats")]
+    #[test]
+    fn test_shrink_stats() {
+        // 计数器为全局共享，并行运行的其它测试也可能使其增加，因此只检查下界
+        Fraction::reset_shrink_stats();
+        let x = Fraction::new(i32::MAX - 2, i32::MAX - 1);
+        let mut y = x;
+        for _ in 0..10 {
+            y *= x;
+        }
+        assert!(Fraction::shrink_approximation_count() >= 10);
+
+        let count = Fraction::shrink_approximation_count();
+        let _ = Fraction::new(155937625, 24970004) - Fraction::new(2103597937, 336845254);
+        assert!(Fraction::shrink_approximation_count() > count);
+    }
+
+    #[test]
+    fn test_to_float_rounded() {
+        let cases = [(2000000028, 1999999915), (2000000070, 1999999789), (2000000196, 1999999411)];
+        for (n, d) in cases {
+            let x = Fraction::new(n, d);
+            assert_ne!(x.to_f32(), x.to_f32_rounded());
+            assert_eq!((-x).to_f32_rounded(), -x.to_f32_rounded());
+            // 正确舍入的结果与精确值之差不超过半个 ulp
+            let r = x.to_f32_rounded();
+            let ulp = f32::from_bits(r.to_bits() + 1) - r;
+            assert!((r as f64 - n as f64 / d as f64).abs() <= ulp as f64 / 2.0);
+        }
+
+        for _ in 0..1000 {
+            let x = random_fraction();
+            assert_eq!(x.to_f64_rounded(), x.to_f64());
+        }
+
+        // 以整数精确比较：|n/d - r| 不大于 |n/d - r 的相邻值|
+        let correctly_rounded = |n: i32, d: i32, r: f32| {
+            let bits = r.abs().to_bits();
+            let (m, e) = ((bits & 0x7fffff | 1 << 23) as i128, ((bits >> 23) as i32) - 127 - 23);
+            // 以 2^(e-1) 为单位，相邻值在 2 的幂处间距减半
+            let prev = if m == 1 << 23 { 2 * m - 1 } else { 2 * m - 2 };
+            let dist = |c: i128| {
+                let (n, d) = (n.unsigned_abs() as i128, d as i128);
+                if e >= 1 { (n - ((c * d) << (e - 1))).abs() } else { ((n << (1 - e)) - c * d).abs() }
+            };
+            dist(2 * m) <= dist(prev) && dist(2 * m) <= dist(2 * m + 2)
+        };
+        // 经由 f64 时被舍入到两个 f32 的中点，再平局取偶得到 1.0
+        let x = Fraction::new(1073741887, 1073741823);
+        assert_eq!(x.to_f32_rounded(), 1.0000001);
+        assert_eq!(x.to_f64_rounded() as f32, 1.0);
+        assert!(correctly_rounded(1073741887, 1073741823, 1.0000001));
+        assert!(!correctly_rounded(1073741887, 1073741823, 1.0));
+        // 平局取偶
+        assert_eq!(Fraction::new(16777217, 2).to_f32_rounded(), 8388608.0);
+        assert_eq!(Fraction::new(16777219, 2).to_f32_rounded(), 8388610.0);
+        assert_eq!(Fraction::MAX.to_f32_rounded(), 2147483648.0);
+        assert_eq!(Fraction::MIN_POSITIVE.to_f32_rounded(), (1.0 / i32::MAX as f64) as f32);
+        for _ in 0..10000 {
+            let (n, d) = (rand::random_range(-i32::MAX..i32::MAX), rand::random_range(1..i32::MAX));
+            let x = Fraction::new(n, d);
+            if !x.is_zero() {
+                let r = x.to_f32_rounded();
+                assert!(correctly_rounded(x.numerator(), x.denominator(), r), "{} -> {}", x, r);
+                assert_eq!(r.is_sign_negative(), n < 0);
+            }
+        }
+        assert_eq!(Fraction::MIN_POSITIVE.to_f64_rounded(), 1.0 / i32::MAX as f64);
+        assert_eq!(Fraction::INFINITY.to_f32_rounded(), f32::INFINITY);
+        assert!(Fraction::NAN.to_f64_rounded().is_nan());
+        assert_eq!(Fraction::ZERO.to_f64_rounded(), 0.0);
+    }
+
+    #[test]
+    fn test_min_fraction_heap() {
+        use std::collections::BinaryHeap;
+
+        let values = [
+            Fraction::new(3, 4), Fraction::NAN, Fraction::new(-5, 2), Fraction::INFINITY, Fraction::ZERO, 
+            Fraction::new(1, 3), Fraction::NEG_INFINITY, Fraction::new(2, 6), Fraction::MIN_POSITIVE
+        ];
+        let mut heap: BinaryHeap<MinFraction> = values.iter().copied().map(MinFraction::from).collect();
+
+        let mut popped = Vec::new();
+        while let Some(x) = heap.pop() {
+            popped.push(Fraction::from(x));
+        }
+        assert_eq!(&popped[..8], &[
+            Fraction::NEG_INFINITY, Fraction::new(-5, 2), Fraction::ZERO, Fraction::MIN_POSITIVE, 
+            Fraction::new(1, 3), Fraction::new(1, 3), Fraction::new(3, 4), Fraction::INFINITY
+        ]);
+        assert!(popped[8].is_nan());
+
+        assert_eq!(MinFraction(Fraction::NAN), MinFraction(Fraction::NAN));
+        assert!(MinFraction(Fraction::new(1, 2)) > MinFraction(Fraction::new(2, 3)));
+    }
+
+    #[test]
+    fn test_from_unicode() {
+        assert_eq!(Fraction::from_unicode("½"), Some(Fraction::new(1, 2)));
+        assert_eq!(Fraction::from_unicode("⅝"), Some(Fraction::new(5, 8)));
+        assert_eq!(Fraction::from_unicode("¼"), Some(Fraction::new(1, 4)));
+        assert_eq!(Fraction::from_unicode("⅒"), Some(Fraction::new(1, 10)));
+        assert_eq!(Fraction::from_unicode("\u{215E}"), Some(Fraction::new(7, 8)));
+
+        assert_eq!(Fraction::from_unicode("↉"), None);
+        assert_eq!(Fraction::from_unicode("⅟"), None);
+        assert_eq!(Fraction::from_unicode("½½"), None);
+        assert_eq!(Fraction::from_unicode("1½"), None);
+        assert_eq!(Fraction::from_unicode(""), None);
+    }
+
+    #[test]
+    fn test_to_unicode() {
+        assert_eq!(Fraction::new(1, 4).to_unicode().as_deref(), Some("¼"));
+        assert_eq!(Fraction::new(3, 4).to_unicode().as_deref(), Some("¾"));
+        assert_eq!(Fraction::new(6, 16).to_unicode().as_deref(), Some("⅜"));
+        assert_eq!(Fraction::new(2, 5).to_unicode().as_deref(), Some("⅖"));
+        assert_eq!(Fraction::new(3, 7).to_unicode(), None);
+        assert_eq!(Fraction::new(-1, 2).to_unicode(), None);
+        assert_eq!(Fraction::from(1).to_unicode(), None);
+        assert_eq!(Fraction::ZERO.to_unicode(), None);
+        assert_eq!(Fraction::INFINITY.to_unicode(), None);
+        assert_eq!(Fraction::NAN.to_unicode(), None);
+
+        for glyph in "¼½¾⅐⅑⅒⅓⅔⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞".chars() {
+            let glyph = glyph.to_string();
+            assert_eq!(Fraction::from_unicode(&glyph).unwrap().to_unicode(), Some(glyph));
+        }
+    }
+
+    #[test]
+    fn test_wrap_unit() {
+        assert_eq!(Fraction::new(5, 3).wrap_unit(), Fraction::new(2, 3));
+        assert_eq!(Fraction::from(3).wrap_unit(), Fraction::ZERO);
+        assert_eq!(Fraction::new(-1, 3).wrap_unit(), Fraction::new(2, 3));
+        assert_eq!(Fraction::new(-7, 2).wrap_unit(), Fraction::new(1, 2));
+        assert_eq!(Fraction::from(-2).wrap_unit(), Fraction::ZERO);
+        assert_eq!(Fraction::new(1, 4).wrap_unit(), Fraction::new(1, 4));
+        assert_eq!(Fraction::ZERO.wrap_unit(), Fraction::ZERO);
+        assert_eq!(Fraction::MIN.wrap_unit(), Fraction::ZERO);
+        assert_eq!(Fraction::new(-1, i32::MAX).wrap_unit(), Fraction::new(i32::MAX - 1, i32::MAX));
+        assert!(Fraction::INFINITY.wrap_unit().is_nan());
+        assert!(Fraction::NEG_INFINITY.wrap_unit().is_nan());
+        assert!(Fraction::NAN.wrap_unit().is_nan());
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..1000));
+            let w = x.wrap_unit();
+            assert!(w >= Fraction::ZERO && w < Fraction::from(1));
+            assert_eq!((x - w).denominator(), 1);
+        }
+    }
+
+    #[test]
+    fn test_to_common_denominator() {
+        let values = [Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 6)];
+        assert_eq!(Fraction::to_common_denominator(&values), Some((6, vec![3, 2, 1])));
+
+        let values = [Fraction::new(5, 12), Fraction::new(-7, 18), Fraction::from(2)];
+        let (denom, numes) = Fraction::to_common_denominator(&values).unwrap();
+        assert_eq!(denom, 36);
+        assert_eq!(numes, [15, -14, 72]);
+        for (v, n) in values.iter().zip(numes) {
+            assert_eq!(*v, Fraction::new(n, denom));
+        }
+
+        assert_eq!(Fraction::to_common_denominator(&[]), Some((1, vec![])));
+        // 分母的最小公倍数超出 i32
+        assert_eq!(Fraction::to_common_denominator(&[Fraction::new(1, 65537), Fraction::new(1, 65539)]), None);
+        // 分母在范围内，但分子溢出
+        assert_eq!(Fraction::to_common_denominator(&[Fraction::new(i32::MAX - 2, 3), Fraction::new(1, 2)]), None);
+        assert_eq!(Fraction::to_common_denominator(&[Fraction::new(1, 2), Fraction::INFINITY]), None);
+        assert_eq!(Fraction::to_common_denominator(&[Fraction::NAN]), None);
+    }
+
+    #[test]
+    fn test_stern_brocot_path() {
+        // 由左右边界的中间分数沿路径下降
+        let descend = |path: &[bool]| {
+            let ((mut a, mut b), (mut c, mut d)) = ((0, 1), (1, 0));
+            let (mut p, mut q) = (1, 1);
+            for &right in path {
+                if right {
+                    (a, b) = (p, q);
+                } else {
+                    (c, d) = (p, q);
+                }
+                (p, q) = (a + c, b + d);
+            }
+            Fraction::new(p, q)
+        };
+
+        let path = Fraction::new(2, 3).stern_brocot_path().unwrap();
+        assert_eq!(path, [false, true]);
+        assert_eq!(descend(&path), Fraction::new(2, 3));
+
+        for x in [Fraction::new(355, 113), Fraction::new(13, 21), Fraction::from(4), Fraction::new(1, 5), Fraction::new(99, 100)] {
+            let path = x.stern_brocot_path().unwrap();
+            assert_eq!(path.len() as i64, x.cf_sum() - 1);
+            assert_eq!(descend(&path), x);
+        }
+
+        assert_eq!(Fraction::from(1).stern_brocot_path(), Some(vec![]));
+        assert_eq!(Fraction::from(3).stern_brocot_path(), Some(vec![true, true]));
+        assert_eq!(Fraction::new(1, 3).stern_brocot_path(), Some(vec![false, false]));
+        assert_eq!(Fraction::ZERO.stern_brocot_path(), None);
+        assert_eq!(Fraction::new(-2, 3).stern_brocot_path(), None);
+        assert_eq!(Fraction::INFINITY.stern_brocot_path(), None);
+        assert_eq!(Fraction::NAN.stern_brocot_path(), None);
+
+        // 路径长度的上限
+        let limit = Fraction::STERN_BROCOT_PATH_LIMIT as i32;
+        assert_eq!(Fraction::new(1, limit + 1).stern_brocot_path().map(|path| path.len()), Some(limit as usize));
+        assert_eq!(Fraction::new(1, limit + 2).stern_brocot_path(), None);
+        assert_eq!(Fraction::from(limit + 2).stern_brocot_path(), None);
+        assert_eq!(Fraction::MIN_POSITIVE.stern_brocot_path(), None);
+        assert_eq!(Fraction::MAX.stern_brocot_path(), None);
+    }
+
+    #[test]
+    fn test_from_stern_brocot_path() {
+        assert_eq!(Fraction::from_stern_brocot_path(&[]), Fraction::from(1));
+        assert_eq!(Fraction::from_stern_brocot_path(&[true, true, true]), Fraction::from(4));
+        assert_eq!(Fraction::from_stern_brocot_path(&[false, false]), Fraction::new(1, 3));
+
+        for x in [Fraction::new(2, 3), Fraction::new(355, 113), Fraction::new(89, 144), Fraction::new(1, 100), Fraction::PI] {
+            assert_eq!(Fraction::from_stern_brocot_path(&x.stern_brocot_path().unwrap()), x);
+        }
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(1..100000), rand::random_range(1..100000));
+            assert_eq!(Fraction::from_stern_brocot_path(&x.stern_brocot_path().unwrap()), x);
+        }
+
+        // 超出 i32 范围的结点被 shrink
+        assert_eq!(Fraction::from_stern_brocot_path(&vec![false; 100000]), Fraction::new(1, 100001));
+        let zigzag: Vec<bool> = (0..200).map(|i| i % 2 == 0).collect();
+        let phi = Fraction::from_stern_brocot_path(&zigzag);
+        assert!((phi - Fraction::new(1346269, 832040)).abs() < Fraction::new(1, 1_000_000_000));
+    }
+
+    #[test]
+    fn test_try_from_f64() {
+        assert_eq!(Fraction::try_from(f64::NAN), Err(ConversionError::NaNConversion));
+        assert_eq!(Fraction::try_from(f64::INFINITY), Err(ConversionError::InfiniteConversion));
+        assert_eq!(Fraction::try_from(f64::NEG_INFINITY), Err(ConversionError::InfiniteConversion));
+        assert_eq!(Fraction::try_from(f64::MAX), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from(-1e20), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from(i32::MAX as f64), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from(f64::MIN_POSITIVE), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from(-1e-12), Err(ConversionError::OutOfRangeError));
+
+        assert_eq!(Fraction::try_from(-0.0), Ok(Fraction::ZERO));
+        assert_eq!(Fraction::try_from(-2.5), Ok(Fraction::new(-5, 2)));
+        assert_eq!(Fraction::try_from(0.1), Ok(Fraction::new(1, 10)));
+        assert_eq!(Fraction::try_from(2147483646.2), Ok(Fraction::MAX));
+        assert_eq!(Fraction::try_from(-2147483647.0), Ok(Fraction::MIN));
+        assert_eq!(Fraction::try_from(-2147483648.0), Err(ConversionError::OutOfRangeError));
+        assert_eq!(Fraction::try_from(1.0 / i32::MAX as f64), Ok(Fraction::MIN_POSITIVE));
+    }
+
+    #[test]
+    fn test_named_constants() {
+        assert_eq!(Fraction::ONE, Fraction::from(1));
+        assert_eq!(Fraction::HALF, Fraction::new(1, 2));
+        assert_eq!(Fraction::TWO, Fraction::from(2));
+        assert_eq!(Fraction::epsilon(), Fraction::MIN_POSITIVE);
+        for x in [Fraction::ONE, Fraction::HALF, Fraction::TWO, Fraction::epsilon()] {
+            assert!(x.validate_invariants());
+        }
+        assert_eq!(Fraction::HALF + Fraction::HALF, Fraction::ONE);
+        assert_eq!(Fraction::ONE / Fraction::HALF, Fraction::TWO);
+
+        // 以 epsilon 为收敛条件的牛顿迭代
+        let n = Fraction::from(2);
+        let mut prev = Fraction::ONE;
+        let mut curr = (n / prev + prev) * Fraction::HALF;
+        while (curr - prev).abs() > Fraction::epsilon() {
+            prev = curr;
+            curr = (n / prev + prev) * Fraction::HALF;
+        }
+        assert!((curr * curr - n).abs() < Fraction::new(1, 1_000_000_000));
+    }
+
+    #[test]
+    fn test_ipow_frac() {
+        assert_eq!(Fraction::from(8).ipow_frac(Fraction::new(1, 3)), Some(2));
+        assert_eq!(Fraction::from(9).ipow_frac(Fraction::new(1, 2)), Some(3));
+        assert_eq!(Fraction::from(2).ipow_frac(Fraction::new(1, 2)), None);
+        assert_eq!(Fraction::from(27).ipow_frac(Fraction::new(4, 3)), Some(81));
+        assert_eq!(Fraction::from(-27).ipow_frac(Fraction::new(1, 3)), Some(-3));
+        assert_eq!(Fraction::from(-4).ipow_frac(Fraction::new(1, 2)), None);
+        assert_eq!(Fraction::new(1, 8).ipow_frac(Fraction::new(-1, 3)), Some(2));
+        assert_eq!(Fraction::new(1, 8).ipow_frac(Fraction::new(1, 3)), None);
+        assert_eq!(Fraction::from(4).ipow_frac(Fraction::new(-1, 2)), None);
+        assert_eq!(Fraction::from(1).ipow_frac(Fraction::new(-7, 9)), Some(1));
+        assert_eq!(Fraction::from(2).ipow_frac(Fraction::from(30)), Some(1 << 30));
+        assert_eq!(Fraction::from(2).ipow_frac(Fraction::from(31)), None);
+        assert_eq!(Fraction::from(-2).ipow_frac(Fraction::from(31)), Some(i32::MIN));
+        assert_eq!(Fraction::from(65536).ipow_frac(Fraction::new(1, 16)), Some(2));
+
+        assert_eq!(Fraction::new(3, 7).ipow_frac(Fraction::ZERO), Some(1));
+        assert_eq!(Fraction::ZERO.ipow_frac(Fraction::ZERO), Some(1));
+        assert_eq!(Fraction::ZERO.ipow_frac(Fraction::new(1, 2)), Some(0));
+        assert_eq!(Fraction::ZERO.ipow_frac(Fraction::new(-1, 2)), None);
+        assert_eq!(Fraction::INFINITY.ipow_frac(Fraction::ONE), None);
+        assert_eq!(Fraction::from(4).ipow_frac(Fraction::NAN), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let (a, b) = (Fraction::new(1, 3), Fraction::new(1, 2));
+        assert_eq!(a.compare(&b), Comparison::Less);
+        assert_eq!(b.compare(&a), Comparison::Greater);
+        assert_eq!(a.compare(&Fraction::new(2, 6)), Comparison::Equal);
+        assert_eq!(Fraction::NEG_INFINITY.compare(&Fraction::MIN), Comparison::Less);
+        assert_eq!(Fraction::INFINITY.compare(&Fraction::INFINITY), Comparison::Equal);
+        assert_eq!(Fraction::NAN.compare(&a), Comparison::Unordered);
+        assert_eq!(a.compare(&Fraction::NAN), Comparison::Unordered);
+        assert_eq!(Fraction::NAN.compare(&Fraction::NAN), Comparison::Unordered);
+    }
+
+    #[test]
+    fn test_to_decimal_places() {
+        assert_eq!(Fraction::new(1, 3).to_decimal_places(2), Fraction::new(33, 100));
+        assert_eq!(Fraction::new(1, 3).to_decimal_places(4), Fraction::new(3333, 10000));
+        assert_eq!(Fraction::new(2, 7).to_decimal_places(2), Fraction::new(29, 100));
+        assert_eq!(Fraction::new(2, 7).to_decimal_places(4), Fraction::new(2857, 10000));
+        assert_eq!(Fraction::new(-2, 7).to_decimal_places(4), Fraction::new(-2857, 10000));
+
+        assert_eq!(Fraction::new(3, 8).to_decimal_places(2), Fraction::new(38, 100));
+        assert_eq!(Fraction::new(5, 8).to_decimal_places(2), Fraction::new(62, 100));
+        assert_eq!(Fraction::new(5, 2).to_decimal_places(0), Fraction::from(2));
+        assert_eq!(Fraction::new(1, 4).to_decimal_places(9), Fraction::new(1, 4));
+        assert_eq!(Fraction::MIN_POSITIVE.to_decimal_places(9), Fraction::ZERO);
+        assert_eq!(Fraction::MAX.to_decimal_places(9), Fraction::MAX);
+        assert_eq!(Fraction::ZERO.to_decimal_places(3), Fraction::ZERO);
+        assert_eq!(Fraction::INFINITY.to_decimal_places(3), Fraction::INFINITY);
+        assert!(Fraction::NAN.to_decimal_places(3).is_nan());
+        assert!(std::panic::catch_unwind(|| Fraction::new(1, 3).to_decimal_places(10)).is_err());
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(Fraction::from_digits(&[1, 2], &[5], 10), Ok(Fraction::new(25, 2)));
+        assert_eq!(Fraction::from_digits(&[], &[3, 3, 3], 10), Ok(Fraction::new(333, 1000)));
+        assert_eq!(Fraction::from_digits(&[0], &[1, 0, 0, 0], 10), Ok(Fraction::new(1, 10)));
+        assert_eq!(Fraction::from_digits(&[2, 1, 4, 7, 4, 8, 3, 6, 4, 6], &[], 10), Ok(Fraction::MAX));
+        assert_eq!(Fraction::from_digits(&[2, 1, 4, 7, 4, 8, 3, 6, 4, 7], &[], 10), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::from_digits(&[], &[0; 30], 10), Ok(Fraction::ZERO));
+        assert_eq!(Fraction::from_digits(&[1], &[0; 30], 10), Ok(Fraction::ONE));
+        assert_eq!(Fraction::from_digits(&[1], &[0; 40], 10), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::from_digits(&[10], &[], 10), Err(ParseFractionError::InvalidInteger));
+
+        assert_eq!(Fraction::from_digits(&[1, 0, 1], &[0, 1], 2), Ok(Fraction::new(21, 4)));
+        assert_eq!(Fraction::from_digits(&[], &[1; 30], 2), Ok(Fraction::new((1 << 30) - 1, 1 << 30)));
+        assert_eq!(Fraction::from_digits(&[], &[1; 31], 2), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::from_digits(&[1; 31], &[], 2), Err(ParseFractionError::InvalidInteger));
+        assert_eq!(Fraction::from_digits(&[0xf, 0xf], &[0x8], 16), Ok(Fraction::new(511, 2)));
+        assert_eq!(Fraction::from_digits(&[35], &[], 36), Ok(Fraction::from(35)));
+
+        assert_eq!(Fraction::from_digits(&[], &[], 10), Err(ParseFractionError::Empty));
+        assert!(std::panic::catch_unwind(|| Fraction::from_digits(&[1], &[], 37)).is_err());
+    }
+
+    #[test]
+    fn test_equals_int() {
+        assert!(Fraction::from(5).equals_int(5));
+        assert!(Fraction::new(10, 2).equals_int(5));
+        assert!(!Fraction::new(1, 2).equals_int(0));
+        assert!(Fraction::new(-12, 4).equals_int(-3));
+        assert!(!Fraction::new(-12, 4).equals_int(3));
+        assert!(Fraction::new(0, 7).equals_int(0));
+        assert!(Fraction::MAX.equals_int(i32::MAX - 1));
+        assert!(Fraction::MIN.equals_int(i32::MIN + 1));
+        assert!(!Fraction::INFINITY.equals_int(i32::MAX));
+        assert!(!Fraction::NEG_INFINITY.equals_int(i32::MIN));
+        assert!(!Fraction::NAN.equals_int(0));
+    }
+
+    #[test]
+    fn test_basis_points() {
+        assert_eq!(Fraction::from_basis_points(2500), Fraction::new(1, 4));
+        assert_eq!(Fraction::from_basis_points(10000), Fraction::ONE);
+        assert_eq!(Fraction::from_basis_points(0), Fraction::ZERO);
+        assert_eq!(Fraction::from_basis_points(-75), Fraction::new(-3, 400));
+
+        assert_eq!(Fraction::new(1, 3).to_basis_points(), Some(3333));
+        assert_eq!(Fraction::new(2, 3).to_basis_points(), Some(6667));
+        assert_eq!(Fraction::new(1, 20000).to_basis_points(), Some(0));
+        assert_eq!(Fraction::new(3, 20000).to_basis_points(), Some(2));
+        assert_eq!(Fraction::new(-3, 20000).to_basis_points(), Some(-2));
+        assert_eq!(Fraction::from(214748).to_basis_points(), Some(2147480000));
+        assert_eq!(Fraction::from(214749).to_basis_points(), None);
+        assert_eq!(Fraction::INFINITY.to_basis_points(), None);
+        assert_eq!(Fraction::NAN.to_basis_points(), None);
+
+        for _ in 0..1000 {
+            let bps = rand::random_range(-200000..200000);
+            assert_eq!(Fraction::from_basis_points(bps).to_basis_points(), Some(bps));
+        }
+    }
+
+    #[test]
+    fn test_approx_constant() {
+        use std::f64::consts::PI;
+
+        assert_eq!(Fraction::approx_constant(PI, 2e-3), Fraction::new(22, 7));
+        assert_eq!(Fraction::approx_constant(PI, 1e-3), Fraction::new(201, 64));
+        assert_eq!(Fraction::approx_constant(PI, 1e-6), Fraction::new(355, 113));
+        assert_eq!(Fraction::approx_constant(PI, 1e-9), Fraction::new(103993, 33102));
+
+        // 结果是满足误差的分母最小的分数
+        for max_error in [1e-2, 1e-4, 1e-7, 1e-10] {
+            let x = Fraction::approx_constant(PI, max_error);
+            assert!((x.to_f64() - PI).abs() <= max_error);
+            let smaller = Fraction::best_rational(PI, x.denominator() - 1);
+            assert!((smaller.to_f64() - PI).abs() > max_error);
+        }
+
+        assert_eq!(Fraction::approx_constant(0.5, 0.0), Fraction::HALF);
+        assert_eq!(Fraction::approx_constant(-2.4, 0.5), Fraction::from(-2));
+        assert_eq!(Fraction::approx_constant(PI, -1.0), Fraction::best_rational(PI, i32::MAX));
+        assert_eq!(Fraction::approx_constant(f64::INFINITY, 1e-3), Fraction::INFINITY);
+        assert!(Fraction::approx_constant(f64::NAN, 1e-3).is_nan());
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Fraction::new(155937625, 24970004);
+        let b = Fraction::new(2103597937, 336845254);
+        assert_eq!(f64::from(a - b), 0.0);
+        let gap = 2.0 / (24970004.0 * 336845254.0);
+        assert!((a.distance(&b) - gap).abs() <= gap * 1e-15);
+        assert_eq!(a.distance(&b), b.distance(&a));
+        assert_eq!(a.distance(&a), 0.0);
+
+        assert_eq!(Fraction::MAX.distance(&Fraction::MIN), (i32::MAX - 1) as f64 * 2.0 + 1.0);
+        assert_eq!(Fraction::MIN_POSITIVE.distance(&Fraction::ZERO), 1.0 / i32::MAX as f64);
+        assert_eq!(Fraction::INFINITY.distance(&Fraction::ONE), f64::INFINITY);
+        assert_eq!(Fraction::ONE.distance(&Fraction::NEG_INFINITY), f64::INFINITY);
+        assert_eq!(Fraction::INFINITY.distance(&Fraction::NEG_INFINITY), f64::INFINITY);
+        assert!(Fraction::INFINITY.distance(&Fraction::INFINITY).is_nan());
+        assert!(Fraction::NAN.distance(&Fraction::ZERO).is_nan());
+    }
+
+    #[test]
+    fn test_with_sign_and_magnitude() {
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..100000));
+            // 由符号与绝对值重新构造
+            assert_eq!(Fraction::ONE.with_sign(x.is_negative()).with_magnitude(x), x);
+            assert_eq!(x.abs().with_sign(x.is_negative()), x);
+            assert_eq!(x.with_sign(true), -x.abs());
+            assert_eq!(x.with_sign(false), x.abs());
+            assert_eq!(x.with_magnitude(x), x);
+        }
+
+        assert_eq!(Fraction::MIN.with_sign(true), Fraction::MIN);
+        assert_eq!(Fraction::MIN.with_sign(false), Fraction::INFINITY);
+        assert_eq!(Fraction::MAX.with_sign(true), Fraction::from(1 - i32::MAX));
+        assert_eq!(Fraction::NEG_INFINITY.with_sign(false), Fraction::INFINITY);
+        assert_eq!(Fraction::NEG_INFINITY.with_sign(true), Fraction::NEG_INFINITY);
+        assert!(Fraction::NAN.with_sign(true).is_nan());
+
+        assert_eq!(Fraction::ZERO.with_magnitude(Fraction::new(-2, 3)), Fraction::new(2, 3));
+        assert_eq!(Fraction::NEG_INFINITY.with_magnitude(Fraction::HALF), Fraction::new(-1, 2));
+        assert!(Fraction::NAN.with_magnitude(Fraction::ONE).is_nan());
+        assert!(Fraction::ONE.with_magnitude(Fraction::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_checked_mul_add() {
+        // Horner 求值 x^3 - 2x + 1/3，x = 5/6
+        let coefficients = [Fraction::ONE, Fraction::ZERO, Fraction::from(-2), Fraction::new(1, 3)];
+        let x = Fraction::new(5, 6);
+        let exact = coefficients[1..].iter().try_fold(coefficients[0], |acc, &c| acc.checked_mul_add(x, c));
+        assert_eq!(exact, Some(Fraction::new(-163, 216)));
+        assert_eq!(exact.unwrap(), x * x * x - x * 2 + Fraction::new(1, 3));
+
+        for _ in 0..1000 {
+            let p = Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000));
+            let q = Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000));
+            let r = Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000));
+            assert_eq!(p.checked_mul_add(q, r), Some(p.mul_add(q, r)));
+        }
+
+        // 最终结果需要 shrink
+        let tiny = Fraction::new(1, 65537);
+        assert_eq!(tiny.checked_mul_add(tiny, Fraction::ONE), None);
+        // 乘积需要 shrink，即使最终结果可以表示
+        let big = Fraction::from(65536);
+        assert_eq!(big.checked_mul_add(big, Fraction::from(-65536)), None);
+        assert_eq!(Fraction::MAX.checked_mul_add(Fraction::ONE, Fraction::ONE), None);
+        assert_eq!(Fraction::MAX.checked_mul_add(Fraction::ONE, Fraction::from(-1)), Some(Fraction::from(i32::MAX - 2)));
+
+        assert_eq!(Fraction::ZERO.checked_mul_add(Fraction::MAX, Fraction::ZERO), Some(Fraction::ZERO));
+        assert_eq!(Fraction::INFINITY.checked_mul_add(Fraction::ONE, Fraction::ZERO), None);
+        assert_eq!(Fraction::ONE.checked_mul_add(Fraction::ONE, Fraction::NAN), None);
+    }
+
+    #[test]
+    fn test_lowest_terms() {
+        assert!(!Fraction::new_unchecked(6, 4).is_lowest_terms());
+        assert!(!Fraction::new_unchecked(-6, 4).is_lowest_terms());
+        assert!(!Fraction::new_unchecked(7, 7).is_lowest_terms());
+        assert!(!Fraction::new_unchecked(0, 3).is_lowest_terms());
+        assert!(Fraction::new_unchecked(0, 1).is_lowest_terms());
+        assert!(Fraction::new_unchecked(3, -4).is_lowest_terms());
+        assert!(Fraction::new_unchecked(0, 0).is_lowest_terms());
+        assert!(!Fraction::new_unchecked(5, 0).is_lowest_terms());
+        assert!(!Fraction::new_unchecked(i32::MIN, 2).is_lowest_terms());
+
+        for _ in 0..1000 {
+            let (n, d) = (rand::random_range(-10000..10000), rand::random_range(-10000..10000));
+            let raw = Fraction::new_unchecked(n, d);
+            let reduced = raw.to_lowest_terms();
+            assert!(reduced.is_lowest_terms());
+            assert!(reduced.validate_invariants());
+            assert!(reduced.eq_exact(&Fraction::new(n, d)));
+            assert_eq!(raw.is_lowest_terms(), Fraction::gcd_extended(n, d).0.abs() == 1 || d == 0 && n == 0);
+        }
+
+        for x in [Fraction::ZERO, Fraction::INFINITY, Fraction::NEG_INFINITY, Fraction::NAN, Fraction::MAX, Fraction::MIN_POSITIVE] {
+            assert!(x.is_lowest_terms());
+        }
+    }
+
+    #[test]
+    fn test_fit_bits() {
+        let pi = Fraction::new(355, 113);
+        let fitted = pi.fit_bits(8, 8);
+        assert!(fitted.numerator().abs() < 128 && fitted.denominator() < 256);
+        assert_eq!(fitted, Fraction::new(22, 7));
+
+        // 与穷举所有满足上限的分数得到的最小距离比较
+        let distance = |x: Fraction, n: i32, d: i32| {
+            (x.numerator() as i64 * d as i64 - n as i64 * x.denominator() as i64).abs() as f64 / (x.denominator() as f64 * d as f64)
+        };
+        for (num_bits, den_bits) in [(6, 5), (8, 8), (5, 9), (10, 3)] {
+            let (nume_limit, deno_limit) = ((1 << (num_bits - 1)) - 1, (1 << den_bits) - 1);
+            for _ in 0..50 {
+                let x = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..100000));
+                let y = x.fit_bits(num_bits, den_bits);
+                assert!(y.numerator().abs() <= nume_limit && y.denominator() <= deno_limit);
+                let best = (-nume_limit..=nume_limit)
+                    .flat_map(|n| (1..=deno_limit).map(move |d| (n, d)))
+                    .map(|(n, d)| distance(x, n, d))
+                    .fold(f64::INFINITY, f64::min);
+                assert!(distance(x, y.numerator(), y.denominator()) <= best * (1.0 + 1e-12));
+            }
+        }
+
+        assert_eq!(Fraction::new(3, 7).fit_bits(1, 8), Fraction::ZERO);
+        assert_eq!(Fraction::new(1, 300).fit_bits(8, 8), Fraction::new(1, 255));
+        assert_eq!(Fraction::new(1, 1000).fit_bits(8, 8), Fraction::ZERO);
+        assert_eq!(Fraction::MAX.fit_bits(32, 31), Fraction::MAX);
+        assert_eq!(Fraction::MIN_POSITIVE.fit_bits(32, 31), Fraction::MIN_POSITIVE);
+        assert_eq!(Fraction::INFINITY.fit_bits(4, 4), Fraction::INFINITY);
+        assert!(Fraction::NAN.fit_bits(4, 4).is_nan());
+        assert!(std::panic::catch_unwind(|| Fraction::ONE.fit_bits(0, 4)).is_err());
+    }
+
+    #[test]
+    fn test_to_hms() {
+        assert_eq!(Fraction::new(7265, 2).to_hms(), (1, 0, 32, Fraction::new(1, 2)));
+        assert_eq!(Fraction::new(10001, 3).to_hms(), (0, 55, 33, Fraction::new(2, 3)));
+        assert_eq!(Fraction::from(180).to_hms(), (0, 3, 0, Fraction::ZERO));
+        assert_eq!(Fraction::from(7200).to_hms(), (2, 0, 0, Fraction::ZERO));
+        assert_eq!(Fraction::ZERO.to_hms(), (0, 0, 0, Fraction::ZERO));
+        assert_eq!(Fraction::from(-60).to_hms(), (-1, 59, 0, Fraction::ZERO));
+        assert_eq!(Fraction::new(-3601, 4).to_hms(), (-1, 44, 59, Fraction::new(3, 4)));
+        assert_eq!(Fraction::MAX.to_hms(), (596523, 14, 6, Fraction::ZERO));
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-10000000..10000000), rand::random_range(1..1000));
+            let (h, m, s, rest) = x.to_hms();
+            assert!(m < 60 && s < 60 && rest >= Fraction::ZERO && rest < Fraction::ONE);
+            assert_eq!(Fraction::from(h * 3600 + m as i64 * 60 + s as i64) + rest, x);
+        }
+
+        let (h, m, s, rest) = Fraction::INFINITY.to_hms();
+        assert_eq!((h, m, s), (0, 0, 0));
+        assert!(rest.is_nan());
+        assert!(Fraction::NAN.to_hms().3.is_nan());
+    }
+
+    #[test]
+    fn test_abs_cmp() {
+        use std::cmp::Ordering;
+
+        assert_eq!(Fraction::new(-3, 4).abs_cmp(&Fraction::new(1, 2)), Some(Ordering::Greater));
+        assert_eq!(Fraction::new(1, 2).abs_cmp(&Fraction::new(-3, 4)), Some(Ordering::Less));
+        assert_eq!(Fraction::new(-2, 3).abs_cmp(&Fraction::new(2, 3)), Some(Ordering::Equal));
+        assert_eq!(Fraction::ZERO.abs_cmp(&Fraction::new(-1, i32::MAX)), Some(Ordering::Less));
+        assert_eq!(Fraction::MIN.abs_cmp(&Fraction::MAX), Some(Ordering::Greater));
+        assert_eq!(Fraction::MIN.abs_cmp(&Fraction::INFINITY), Some(Ordering::Less));
+        assert_eq!(Fraction::NEG_INFINITY.abs_cmp(&Fraction::MAX), Some(Ordering::Greater));
+        assert_eq!(Fraction::INFINITY.abs_cmp(&Fraction::NEG_INFINITY), Some(Ordering::Equal));
+        assert_eq!(Fraction::NAN.abs_cmp(&Fraction::INFINITY), None);
+        assert_eq!(Fraction::ONE.abs_cmp(&Fraction::NAN), None);
+
+        for _ in 0..1000 {
+            let x = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..100000));
+            let y = Fraction::new(rand::random_range(-100000..100000), rand::random_range(1..100000));
+            assert_eq!(x.abs_cmp(&y), x.abs().partial_cmp(&y.abs()));
+        }
+    }
+
+    #[test]
+    fn test_kahan_sum() {
+        // 与精确值 p/q 的误差
+        let error = |x: Fraction, p: i128, q: i128| {
+            let (n, d) = (x.numerator() as i128, x.denominator() as i128);
+            (n * q - p * d).abs() as f64 / (d as f64 * q as f64)
+        };
+
+        for count in [10, 100, 1000] {
+            let mut values = vec![Fraction::new(1, 3)];
+            values.extend(std::iter::repeat_n(Fraction::new(1, 1 << 30), count));
+            // 精确值为 1/3 + count/2^30
+            let (p, q) = ((1i128 << 30) + 3 * count as i128, 3i128 << 30);
+
+            let naive = values.iter().fold(Fraction::ZERO, |acc, &x| acc + x);
+            let sum = Fraction::kahan_sum(&values);
+            assert!(error(sum, p, q) <= error(naive, p, q));
+            assert!(error(naive, p, q) > 0.0);
+            assert_eq!(error(sum, p, q), 0.0);
+        }
+
+        for _ in 0..100 {
+            let values: Vec<_> = (0..10)
+                .map(|_| Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000)))
+                .collect();
+            let mut acc = Accumulator::new();
+            values.iter().for_each(|&x| acc.push(x));
+            assert_eq!(Fraction::kahan_sum(&values), acc.finish());
+        }
+
+        assert_eq!(Fraction::kahan_sum(&[]), Fraction::ZERO);
+        assert_eq!(Fraction::kahan_sum(&[Fraction::MAX, Fraction::ONE]), Fraction::INFINITY);
+        assert_eq!(Fraction::kahan_sum(&[Fraction::ONE, Fraction::NEG_INFINITY]), Fraction::NEG_INFINITY);
+        assert!(Fraction::kahan_sum(&[Fraction::INFINITY, Fraction::NEG_INFINITY]).is_nan());
+        assert!(Fraction::kahan_sum(&[Fraction::NAN, Fraction::ONE]).is_nan());
+
+        // 较长的切片中混有 NAN 时，排序的比较函数也不能违反全序
+        for _ in 0..200 {
+            let mut values: Vec<_> = (0..40)
+                .map(|_| Fraction::new(rand::random_range(-1000..1000), rand::random_range(1..1000)))
+                .collect();
+            for _ in 0..rand::random_range(1..5) {
+                values[rand::random_range(0..40)] = Fraction::NAN;
+            }
+            assert!(Fraction::kahan_sum(&values).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_babylonian_step() {
+        let n = Fraction::from(2);
+        let mut prev = Fraction::ONE;
+        let mut curr = Fraction::babylonian_step(n, prev);
+        let mut steps = 0;
+        while (curr - prev).abs() > Fraction::epsilon() {
+            prev = curr;
+            curr = Fraction::babylonian_step(n, prev);
+            steps += 1;
+            assert!(steps < 100, "iteration must terminate under an epsilon stop");
+        }
+        // 与 √2 的误差在 shrink 的精度范围内
+        assert!((curr * curr - n).abs() < Fraction::new(1, 1 << 29));
+        assert!((curr.to_f64() - 2f64.sqrt()).abs() < 1e-15);
+
+        for _ in 0..100 {
+            let n = Fraction::new(rand::random_range(1..100000), rand::random_range(1..1000));
+            let mut guess = if n > Fraction::ONE { n } else { Fraction::ONE };
+            for _ in 0..100 {
+                let next = Fraction::babylonian_step(n, guess);
+                if (next - guess).abs() <= Fraction::epsilon() {
+                    break;
+                }
+                guess = next;
+            }
+            assert!((guess.to_f64() - n.to_f64().sqrt()).abs() < 1e-9);
+        }
+
+        assert_eq!(Fraction::babylonian_step(Fraction::from(100), Fraction::from(10)), Fraction::from(10));
+        assert_eq!(Fraction::babylonian_step(Fraction::from(4), Fraction::from(-1)), Fraction::new(-5, 2));
+        assert_eq!(Fraction::babylonian_step(Fraction::ZERO, Fraction::ONE), Fraction::ZERO);
+        assert!(Fraction::babylonian_step(Fraction::from(-4), Fraction::ONE).is_nan());
+        assert!(Fraction::babylonian_step(Fraction::NAN, Fraction::ONE).is_nan());
+        assert_eq!(Fraction::babylonian_step(Fraction::from(2), Fraction::ZERO), Fraction::INFINITY);
+        assert_eq!(Fraction::babylonian_step(Fraction::from(2), Fraction::INFINITY), Fraction::INFINITY);
+        assert_eq!(Fraction::babylonian_step(Fraction::INFINITY, Fraction::ONE), Fraction::INFINITY);
+    }
+
     #[test]
     fn test_document_in_readme() {
         // safe